@@ -0,0 +1,67 @@
+use rocket::{self, post, State, http::Status, response::status::Custom, serde::json::Json};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::endpoints::api_error::ApiError;
+use crate::model::{league::season::Season, state::league_data::LeagueData};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SeasonSubmission {
+    pub name: String,
+}
+
+#[post("/league/season", format = "json", data = "<submission>")]
+pub fn add_league_season(
+    league_data: &State<LeagueData>,
+    submission: Json<SeasonSubmission>
+) -> Result<Custom<Json<Season>>, ApiError> {
+    let name = submission.name.trim();
+    if name.is_empty() {
+        return Err(ApiError::bad_request("Season name can not be empty."));
+    }
+
+    let season = league_data
+        .update(|league| {
+            let id = league.add_season(name);
+            league.seasons.iter().find(|season| season.id == id).unwrap().clone()
+        })
+        .map_err(|error| ApiError::internal(error.to_string()))?;
+    Ok(Custom(Status::Created, Json(season)))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+    use rocket::uri;
+
+    use crate::test_app;
+    use crate::model::league::season::Season;
+
+    #[test]
+    fn test_add_league_season() {
+        let client = Client::tracked(test_app("add_league_season")).expect("valid rocket instance");
+        let response = client.post(uri!("/league/season"))
+            .header(ContentType::JSON)
+            .body("{\"name\":\"Spring\"}")
+            .dispatch();
+        assert_eq!(response.status(), Status::Created);
+        assert_eq!(response.into_json::<Season>().unwrap(), Season { id: 1, name: "Spring".to_string() });
+
+        let response = client.post(uri!("/league/season"))
+            .header(ContentType::JSON)
+            .body("{\"name\":\"Summer\"}")
+            .dispatch();
+        assert_eq!(response.into_json::<Season>().unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_add_league_season_empty_name() {
+        let client = Client::tracked(test_app("add_league_season_empty_name")).expect("valid rocket instance");
+        let response = client.post(uri!("/league/season"))
+            .header(ContentType::JSON)
+            .body("{\"name\":\"  \"}")
+            .dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+}
@@ -0,0 +1,135 @@
+use rocket::{self, catch, Request, http::Status, response::{self, Responder, status::Custom}, serde::json::Json};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::model::{data_loaders::data_dir::DataLoadError, kingdom::SupplyError, kingdom_generator::KingdomGeneratorError};
+
+/// The JSON body of every error response.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ApiErrorBody {
+    pub status: u16,
+    pub error: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ApiError {
+    pub status: Status,
+    pub message: String,
+}
+
+pub type ApiResult<T> = Result<Json<T>, ApiError>;
+
+impl ApiError {
+    pub fn new(status: Status, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(Status::BadRequest, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(Status::NotFound, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(Status::InternalServerError, message)
+    }
+
+    pub fn body(&self) -> ApiErrorBody {
+        ApiErrorBody {
+            status: self.status.code,
+            error: self.status.reason_lossy().to_string(),
+            message: self.message.clone()
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for ApiError {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        Custom(self.status, Json(self.body())).respond_to(request)
+    }
+}
+
+impl From<KingdomGeneratorError> for ApiError {
+    fn from(error: KingdomGeneratorError) -> Self {
+        ApiError::bad_request(error.to_string())
+    }
+}
+
+impl From<SupplyError> for ApiError {
+    fn from(error: SupplyError) -> Self {
+        ApiError::bad_request(error.to_string())
+    }
+}
+
+impl From<DataLoadError> for ApiError {
+    fn from(error: DataLoadError) -> Self {
+        ApiError::internal(format!("Could not load the card data. {}", error))
+    }
+}
+
+/// Errors raised by rocket itself, such as unmatched routes or malformed query strings, use the same body.
+#[catch(default)]
+pub fn default_catcher(status: Status, request: &Request) -> Custom<Json<ApiErrorBody>> {
+    let message = match status.code {
+        404 => format!("Nothing found at {}.", request.uri().path()),
+        422 => "The request could not be understood.".to_string(),
+        _ => status.reason_lossy().to_string(),
+    };
+    Custom(status, Json(ApiError::new(status, message).body()))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+    use rocket::uri;
+
+    use super::*;
+    use crate::launch_app;
+
+    #[test]
+    fn test_body() {
+        assert_eq!(
+            ApiError::not_found("Unknown card id 5.").body(),
+            ApiErrorBody { status: 404, error: "Not Found".to_string(), message: "Unknown card id 5.".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(ApiError::bad_request("a").status, Status::BadRequest);
+        assert_eq!(ApiError::not_found("a").status, Status::NotFound);
+        assert_eq!(ApiError::internal("a").status, Status::InternalServerError);
+    }
+
+    #[test]
+    fn test_from_supply_error() {
+        assert_eq!(
+            ApiError::from(SupplyError::InvalidPlayerCount(7)),
+            ApiError::bad_request("Player count must be between 2 and 6, got 7.")
+        );
+    }
+
+    #[test]
+    fn test_default_catcher_unknown_route() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/no/such/route")).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.content_type(), Some(ContentType::JSON));
+        assert_eq!(
+            response.into_json::<ApiErrorBody>().unwrap(),
+            ApiErrorBody { status: 404, error: "Not Found".to_string(), message: "Nothing found at /no/such/route.".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_default_catcher_unprocessable() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?require_tag=NotATag")).dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().status, 422);
+    }
+}
@@ -1,4 +1,15 @@
-use rocket::{self, Rocket, Build, fs::{FileServer, relative}};
+use std::path::{Path, PathBuf};
+
+use rocket::{self, Rocket, Build, get, routes, fs::NamedFile, http::ContentType};
+
+use crate::endpoints::scraped_file::open_scraped_file;
+use crate::model::{scrapers::scrape_engine::DEFAULT_OUTPUT_DIR, state::scraped_manifest::ScrapedManifestSnapshot};
+
+/// Sent with the type the art really is, ranked after the card id routes that share the path.
+#[get("/<file_name..>", rank = 4)]
+pub async fn card_art_file(manifest: ScrapedManifestSnapshot, file_name: PathBuf) -> Option<(ContentType, NamedFile)> {
+    open_scraped_file(&manifest, &Path::new(DEFAULT_OUTPUT_DIR).join("cards").join(file_name)).await.ok()
+}
 
 pub trait MountCardArtFileServer {
     fn mount_card_art_file_server(self) -> Rocket<Build>;
@@ -6,7 +17,7 @@ pub trait MountCardArtFileServer {
 
 impl MountCardArtFileServer for Rocket<Build> {
     fn mount_card_art_file_server(self) -> Rocket<Build> {
-        self.mount("/card", FileServer::from(relative!("src/model/scraped_data/cards")))
+        self.mount("/card", routes![card_art_file])
     }    
 }
 
@@ -16,7 +27,7 @@ mod test {
     use std::io::{BufReader, Read};
 
     use rocket::local::blocking::Client;
-    use rocket::http::Status;
+    use rocket::http::{ContentType, Status};
     use rocket::uri;
 
     use crate::launch_app;
@@ -42,6 +53,7 @@ mod test {
         let _ = reader.read_to_end(&mut buffer);
 
         assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::JPEG));
         assert!(response.into_bytes().unwrap() == buffer);
     }
 
@@ -1,34 +1,26 @@
-use rocket::{self, State, response::Redirect};
+use rocket::{self, response::Redirect};
 
-use crate::model::{state::card_data::CardData, card::CardFilepath};
+use crate::endpoints::api_error::ApiError;
+use crate::model::{state::card_data::CardDataSnapshot, card::CardFilepath};
 
 
 #[get("/card/<id>", rank=2)]
-pub fn card_art_from_id(card_data: &State<CardData>,  id: u16) -> Redirect {
-    if card_data.id_card_map.contains_key(&id) {
-        let card = &card_data.id_card_map[&id];
-
-        let edition = match card.editions.last() {
-            Some(edition) => edition,
-            None => &u8::MAX,
-        };
-        let card_file_name = format!("../../card/{}.jpeg",&card_data.id_card_map[&id].get_art_file_name(*edition));
-        return Redirect::to(card_file_name);
-    }
-    Redirect::to("../../card/no_such_card_id")
+pub fn card_art_from_id(card_data: CardDataSnapshot,  id: u16) -> Result<Redirect, ApiError> {
+    let card = card_data.id_card_map.get(&id)
+        .ok_or_else(|| ApiError::not_found(format!("Unknown card id {}.", id)))?;
+    let edition = card.editions.last()
+        .ok_or_else(|| ApiError::not_found(format!("Card {} has no editions.", card.name)))?;
+    Ok(Redirect::to(format!("../../card/{}.jpeg", card.get_art_file_name(*edition))))
 }
 
 #[get("/card/<id>/<edition>", rank=3)]
-pub fn card_art_from_id_with_edition(card_data: &State<CardData>,  id: u16, edition: u8) -> Redirect {
-    if card_data.id_card_map.contains_key(&id) {
-        let card = &card_data.id_card_map[&id];
-        if card.editions.contains(&edition){ 
-            let card_file_name = format!("../../card/{}.jpeg",card.get_art_file_name(edition));
-            return Redirect::to(card_file_name);
-        }        
-        return Redirect::to("../../card/no_such_edition"); 
+pub fn card_art_from_id_with_edition(card_data: CardDataSnapshot,  id: u16, edition: u8) -> Result<Redirect, ApiError> {
+    let card = card_data.id_card_map.get(&id)
+        .ok_or_else(|| ApiError::not_found(format!("Unknown card id {}.", id)))?;
+    if !card.editions.contains(&edition) {
+        return Err(ApiError::not_found(format!("Card {} has no edition {}.", card.name, edition)));
     }
-    Redirect::to("../../card/no_such_card_id")
+    Ok(Redirect::to(format!("../../card/{}.jpeg", card.get_art_file_name(edition))))
 }
 
 
@@ -40,6 +32,7 @@ mod test {
     use rocket::uri;
 
     use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
 
     #[test]
     fn test_card_art_from_id() {
@@ -57,9 +50,8 @@ mod test {
         let client = Client::tracked(launch_app()).expect("valid rocket instance");
         let response = client.get(uri!("/card/65500")).dispatch();
 
-        let headers_location = response.headers().get("Location").next();
-        assert_eq!(response.status(), Status::SeeOther);
-        assert_eq!(headers_location, Some("../../card/no_such_card_id"));
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Unknown card id 65500.");
     }
 
     #[test]
@@ -78,18 +70,16 @@ mod test {
         let client = Client::tracked(launch_app()).expect("valid rocket instance");
         let response = client.get(uri!("/card/65500/1")).dispatch();
 
-        let headers_location = response.headers().get("Location").next();
-        assert_eq!(response.status(), Status::SeeOther);
-        assert_eq!(headers_location, Some("../../card/no_such_card_id"));
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Unknown card id 65500.");
     }
 
     #[test]
     fn test_card_art_from_id_unknowen_edition() {
         let client = Client::tracked(launch_app()).expect("valid rocket instance");
         let response = client.get(uri!("/card/0/3")).dispatch();
-        
-        let headers_location = response.headers().get("Location").next();
-        assert_eq!(response.status(), Status::SeeOther);
-        assert_eq!(headers_location, Some("../../card/no_such_edition"));
+
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Card Copper has no edition 3.");
     }
 }
\ No newline at end of file
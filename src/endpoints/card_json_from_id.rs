@@ -1,14 +1,15 @@
-use rocket::{self, get, State, serde::json::serde_json};
+use rocket::{self, get, serde::json::Json};
 
-use crate::model::state::card_data::CardData;
+use crate::endpoints::api_error::{ApiError, ApiResult};
+use crate::model::{card::Card, state::card_data::CardDataSnapshot};
 
 
 #[get("/card_json/<id>")]
-pub fn card_json_from_id(card_data: &State<CardData>,  id: u16) -> String {
-    if card_data.id_card_map.contains_key(&id) {
-       return serde_json::to_string(&card_data.id_card_map[&id]).unwrap()
+pub fn card_json_from_id(card_data: CardDataSnapshot,  id: u16) -> ApiResult<Card> {
+    match card_data.id_card_map.get(&id) {
+        Some(card) => Ok(Json(card.clone())),
+        None => Err(ApiError::not_found(format!("Unknown card id {}.", id))),
     }
-    "Unknown card id".to_string()
 }
 
 #[cfg(test)]
@@ -18,6 +19,7 @@ mod test {
     use rocket::uri;
 
     use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
 
     #[test]
     fn test_card_json_from_id() {
@@ -25,14 +27,16 @@ mod test {
         let response = client.get(uri!(super::card_json_from_id(101))).dispatch();
         assert_eq!(response.status(), Status::Ok);
         assert_eq!(response.into_string().unwrap(), 
-            "{\"id\":101,\"name\":\"Chapel\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"set_id\":1,\"editions\":[1,2],\"card_tags\":[\"IsAction\",\"Costs2\",\"NetLoseCards\",\"TrashFromHand\",\"TrashMultipleFromHand\",\"TrashTreasre\",\"TrashAction\",\"TrashVictory\",\"TrashCurse\"],\"kingdom_requirements\":[],\"kingdom_synergies\":[],\"kingdom_anti_synergies\":[],\"card_types\":[\"Action\"],\"art_url\":[\"http://wiki.dominionstrategy.com/images/archive/2/29/20161006150258%21Chapel.jpg\",\"http://wiki.dominionstrategy.com/images/2/29/Chapel.jpg\"]}");
+            "{\"id\":101,\"name\":\"Chapel\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"cost\":{\"coins\":2,\"potion\":false,\"debt\":0,\"modifiers\":[]},\"set_id\":1,\"editions\":[1,2],\"card_tags\":[\"IsAction\",\"Costs2\",\"NetLoseCards\",\"TrashFromHand\",\"TrashMultipleFromHand\",\"TrashTreasre\",\"TrashAction\",\"TrashVictory\",\"TrashCurse\"],\"kingdom_requirements\":[],\"kingdom_synergies\":[],\"kingdom_anti_synergies\":[],\"card_types\":[\"Action\"],\"art_url\":[\"http://wiki.dominionstrategy.com/images/archive/2/29/20161006150258%21Chapel.jpg\",\"http://wiki.dominionstrategy.com/images/2/29/Chapel.jpg\"],\"relationships\":{\"setup_card_ids\":[],\"pile_card_ids\":[],\"heirloom_card_id\":null},\"texts\":[{\"edition\":1,\"text\":\"Trash up to 4 cards from your hand.\",\"errata\":[],\"faq\":[]},{\"edition\":2,\"text\":\"Trash up to 4 cards from your hand.\",\"errata\":[],\"faq\":[]}]}");
     }
 
     #[test]
     fn test_card_json_from_id_unknowen_id() {
         let client = Client::tracked(launch_app()).expect("valid rocket instance");
         let response = client.get(uri!(super::card_json_from_id(6550))).dispatch();
-        assert_eq!(response.status(), Status::Ok);
-        assert_eq!(response.into_string().unwrap(), "Unknown card id".to_string());
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(
+            response.into_json::<ApiErrorBody>().unwrap(),
+            ApiErrorBody { status: 404, error: "Not Found".to_string(), message: "Unknown card id 6550.".to_string() });
     }
 }
\ No newline at end of file
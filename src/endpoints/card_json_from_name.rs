@@ -1,14 +1,15 @@
-use rocket::{self, get, State, serde::json::serde_json};
+use rocket::{self, get, serde::json::Json};
 
-use crate::model::state::card_data::CardData;
+use crate::endpoints::api_error::{ApiError, ApiResult};
+use crate::model::{card::Card, state::card_data::CardDataSnapshot};
 
 
 #[get("/card_json/<name>", rank=2)]
-pub fn card_json_from_name(card_data: &State<CardData>,  name: String) -> String {
-    if card_data.name_card_map.contains_key(&name) {
-       return serde_json::to_string(&card_data.name_card_map[&name]).unwrap();
+pub fn card_json_from_name(card_data: CardDataSnapshot,  name: String) -> ApiResult<Card> {
+    match card_data.name_card_map.get(&name) {
+        Some(card) => Ok(Json(card.clone())),
+        None => Err(ApiError::not_found(format!("Unknown card name {}.", name))),
     }
-    "Unknown card name".to_string()
 }
 
 #[cfg(test)]
@@ -18,6 +19,7 @@ mod test {
     use rocket::uri;
 
     use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
 
     #[test]
     fn test_card_json_from_name() {
@@ -25,7 +27,7 @@ mod test {
         let response = client.get(uri!(super::card_json_from_name("Chapel".to_string()))).dispatch();
         assert_eq!(response.status(), Status::Ok);
         assert_eq!(response.into_string().unwrap(), 
-            "{\"id\":101,\"name\":\"Chapel\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"set_id\":1,\"editions\":[1,2],\"card_tags\":[\"IsAction\",\"Costs2\",\"NetLoseCards\",\"TrashFromHand\",\"TrashMultipleFromHand\",\"TrashTreasre\",\"TrashAction\",\"TrashVictory\",\"TrashCurse\"],\"kingdom_requirements\":[],\"kingdom_synergies\":[],\"kingdom_anti_synergies\":[],\"card_types\":[\"Action\"],\"art_url\":[\"http://wiki.dominionstrategy.com/images/archive/2/29/20161006150258%21Chapel.jpg\",\"http://wiki.dominionstrategy.com/images/2/29/Chapel.jpg\"]}");
+            "{\"id\":101,\"name\":\"Chapel\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"cost\":{\"coins\":2,\"potion\":false,\"debt\":0,\"modifiers\":[]},\"set_id\":1,\"editions\":[1,2],\"card_tags\":[\"IsAction\",\"Costs2\",\"NetLoseCards\",\"TrashFromHand\",\"TrashMultipleFromHand\",\"TrashTreasre\",\"TrashAction\",\"TrashVictory\",\"TrashCurse\"],\"kingdom_requirements\":[],\"kingdom_synergies\":[],\"kingdom_anti_synergies\":[],\"card_types\":[\"Action\"],\"art_url\":[\"http://wiki.dominionstrategy.com/images/archive/2/29/20161006150258%21Chapel.jpg\",\"http://wiki.dominionstrategy.com/images/2/29/Chapel.jpg\"],\"relationships\":{\"setup_card_ids\":[],\"pile_card_ids\":[],\"heirloom_card_id\":null},\"texts\":[{\"edition\":1,\"text\":\"Trash up to 4 cards from your hand.\",\"errata\":[],\"faq\":[]},{\"edition\":2,\"text\":\"Trash up to 4 cards from your hand.\",\"errata\":[],\"faq\":[]}]}");
     }
 
     #[test]
@@ -34,14 +36,16 @@ mod test {
         let response = client.get(uri!(super::card_json_from_name("chapel".to_string()))).dispatch();
         assert_eq!(response.status(), Status::Ok);
         assert_eq!(response.into_string().unwrap(), 
-            "{\"id\":101,\"name\":\"Chapel\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"set_id\":1,\"editions\":[1,2],\"card_tags\":[\"IsAction\",\"Costs2\",\"NetLoseCards\",\"TrashFromHand\",\"TrashMultipleFromHand\",\"TrashTreasre\",\"TrashAction\",\"TrashVictory\",\"TrashCurse\"],\"kingdom_requirements\":[],\"kingdom_synergies\":[],\"kingdom_anti_synergies\":[],\"card_types\":[\"Action\"],\"art_url\":[\"http://wiki.dominionstrategy.com/images/archive/2/29/20161006150258%21Chapel.jpg\",\"http://wiki.dominionstrategy.com/images/2/29/Chapel.jpg\"]}");
+            "{\"id\":101,\"name\":\"Chapel\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"cost\":{\"coins\":2,\"potion\":false,\"debt\":0,\"modifiers\":[]},\"set_id\":1,\"editions\":[1,2],\"card_tags\":[\"IsAction\",\"Costs2\",\"NetLoseCards\",\"TrashFromHand\",\"TrashMultipleFromHand\",\"TrashTreasre\",\"TrashAction\",\"TrashVictory\",\"TrashCurse\"],\"kingdom_requirements\":[],\"kingdom_synergies\":[],\"kingdom_anti_synergies\":[],\"card_types\":[\"Action\"],\"art_url\":[\"http://wiki.dominionstrategy.com/images/archive/2/29/20161006150258%21Chapel.jpg\",\"http://wiki.dominionstrategy.com/images/2/29/Chapel.jpg\"],\"relationships\":{\"setup_card_ids\":[],\"pile_card_ids\":[],\"heirloom_card_id\":null},\"texts\":[{\"edition\":1,\"text\":\"Trash up to 4 cards from your hand.\",\"errata\":[],\"faq\":[]},{\"edition\":2,\"text\":\"Trash up to 4 cards from your hand.\",\"errata\":[],\"faq\":[]}]}");
     }
 
     #[test]
     fn test_card_json_from_name_unknowen_name() {
         let client = Client::tracked(launch_app()).expect("valid rocket instance");
         let response = client.get(uri!(super::card_json_from_name("What are you doing? Why on earth is this your card name? Now I need to fix my test!".to_string()))).dispatch();
-        assert_eq!(response.status(), Status::Ok);
-        assert_eq!(response.into_string().unwrap(), "Unknown card name".to_string());
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(
+            response.into_json::<ApiErrorBody>().unwrap().message,
+            "Unknown card name What are you doing? Why on earth is this your card name? Now I need to fix my test!.");
     }
 }
\ No newline at end of file
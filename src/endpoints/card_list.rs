@@ -0,0 +1,165 @@
+use rocket::{self, get, FromForm, serde::json::Json};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::model::{
+    card::Card,
+    card_query::{CardQuery, TagMatch, query_cards},
+    card_tag::CardTag,
+    card_type::CardType,
+    state::card_data::CardDataSnapshot
+};
+use crate::endpoints::random_kingdom::SetEditions;
+
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+pub const MAX_PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Clone, FromForm)]
+pub struct CardListQuery {
+    pub card_type: Option<CardType>,
+    pub tag: Vec<CardTag>,
+    pub tag_match: Option<TagMatch>,
+    pub set_id: Option<u8>,
+    pub edition: Option<u8>,
+    pub supply_card: Option<bool>,
+    pub basic_card: Option<bool>,
+    pub owned: Vec<SetEditions>,
+    pub page: Option<usize>,
+    pub per_page: Option<usize>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CardPage {
+    pub page: usize,
+    pub per_page: usize,
+    pub total: usize,
+    pub cards: Vec<Card>,
+}
+
+/// Pages start at 1.
+#[get("/cards?<query..>")]
+pub fn card_list(card_data: CardDataSnapshot, query: CardListQuery) -> Json<CardPage> {
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let card_query = CardQuery {
+        card_type: query.card_type,
+        card_tags: query.tag,
+        tag_match: query.tag_match.unwrap_or_default(),
+        set_id: query.set_id,
+        edition: query.edition,
+        supply_card: query.supply_card,
+        basic_card: query.basic_card,
+        owned_editions: query.owned.into(),
+    };
+
+    let cards = query_cards(&card_data, &card_query);
+    Json(CardPage {
+        page,
+        per_page,
+        total: cards.len(),
+        cards: cards.into_iter().skip((page - 1).saturating_mul(per_page)).take(per_page).cloned().collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use super::CardPage;
+    use crate::launch_app;
+
+    fn get_card_page(query: &str) -> CardPage {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(format!("/cards{}", query)).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        response.into_json().unwrap()
+    }
+
+    fn names(card_page: &CardPage) -> Vec<&str> {
+        card_page.cards.iter().map(|card| card.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_card_list() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let card_page: CardPage = response.into_json().unwrap();
+        assert_eq!(card_page.page, 1);
+        assert_eq!(card_page.per_page, 20);
+        assert_eq!(card_page.total, 71);
+        assert_eq!(card_page.cards.len(), 20);
+        assert_eq!(card_page.cards[0].name, "Copper");
+    }
+
+    #[test]
+    fn test_card_list_pagination() {
+        let card_page = get_card_page("?page=2&per_page=30");
+        assert_eq!(card_page.cards.len(), 30);
+        assert_eq!(card_page.cards[0].id, 123);
+        let card_page = get_card_page("?page=3&per_page=30");
+        assert_eq!(card_page.cards.len(), 11);
+        assert_eq!(card_page.cards[0].id, 221);
+
+        assert!(get_card_page("?page=5&per_page=30").cards.is_empty());
+        let card_page = get_card_page("?page=18446744073709551615&per_page=100");
+        assert_eq!(card_page.page, usize::MAX);
+        assert!(card_page.cards.is_empty());
+        assert_eq!(get_card_page("?per_page=500").per_page, 100);
+        assert_eq!(get_card_page("?page=0").page, 1);
+    }
+
+    #[test]
+    fn test_card_list_card_type() {
+        let card_page = get_card_page("?card_type=Reaction");
+        assert_eq!(names(&card_page), vec!["Moat", "Secret Chamber", "Diplomat"]);
+    }
+
+    #[test]
+    fn test_card_list_tags_all() {
+        let card_page = get_card_page("?tag=IsAttack&tag=Costs4");
+        assert_eq!(names(&card_page), vec!["Bureaucrat", "Militia", "Spy", "Thief"]);
+    }
+
+    #[test]
+    fn test_card_list_tags_any() {
+        let card_page = get_card_page("?tag=CurseAttack&tag=TrashCurse&tag_match=any");
+        assert_eq!(names(&card_page), vec!["Chapel", "Sentry", "Witch", "Replace", "Torturer"]);
+    }
+
+    #[test]
+    fn test_card_list_set_edition_supply_basic() {
+        assert_eq!(get_card_page("?set_id=0").total, 7);
+        assert_eq!(get_card_page("?set_id=1&edition=2").total, 26);
+        assert_eq!(get_card_page("?set_id=2").total, 32);
+        assert_eq!(get_card_page("?set_id=2&edition=1").total, 25);
+        assert_eq!(get_card_page("?basic_card=true").total, 7);
+        assert_eq!(get_card_page("?supply_card=true&basic_card=false").total, 64);
+    }
+
+    #[test]
+    fn test_card_list_owned_editions() {
+        assert_eq!(get_card_page("?owned=1:2").total, 33);
+        assert_eq!(get_card_page("?owned=1:1,2").total, 39);
+        assert_eq!(get_card_page("?owned=1:1&owned=2:2").total, 58);
+    }
+
+    #[test]
+    fn test_card_list_malformed_owned_editions() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        for query in ["/cards?owned=1", "/cards?owned=1:x", "/cards?owned=a:1"] {
+            let response = client.get(query).dispatch();
+            assert_eq!(response.status(), Status::UnprocessableEntity);
+        }
+    }
+
+    #[test]
+    fn test_card_list_unknown_tag() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards?tag=NotATag")).dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+}
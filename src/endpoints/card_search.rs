@@ -0,0 +1,76 @@
+use rocket::{self, get, serde::json::Json};
+
+use crate::endpoints::api_error::{ApiError, ApiResult};
+use crate::model::{card_search::{CardSearchResult, normalise_card_name, search_cards}, state::card_data::CardDataSnapshot};
+
+pub const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+#[get("/cards/search?<q>&<limit>")]
+pub fn card_search(card_data: CardDataSnapshot, q: &str, limit: Option<usize>) -> ApiResult<Vec<CardSearchResult>> {
+    if normalise_card_name(q).is_empty() {
+        return Err(ApiError::bad_request("Search query must contain a letter or digit."));
+    }
+    Ok(Json(search_cards(&card_data, q, limit.unwrap_or(DEFAULT_SEARCH_LIMIT))))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::card_search::{CardSearchResult, MatchKind};
+
+    #[test]
+    fn test_card_search() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/search?q=throne%20room")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let results: Vec<CardSearchResult> = response.into_json().unwrap();
+        assert_eq!(results[0].card.name, "Throne Room");
+        assert_eq!(results[0].match_kind, MatchKind::Exact);
+    }
+
+    #[test]
+    fn test_card_search_limit() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let results: Vec<CardSearchResult> = client.get(uri!("/cards/search?q=c&limit=1")).dispatch().into_json().unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_card_search_no_results() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/search?q=zzzzzzzz")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_card_search_short_query_not_fuzzy() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/search?q=x")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let results: Vec<CardSearchResult> = response.into_json().unwrap();
+        assert!(results.iter().all(|result| result.match_kind != MatchKind::Fuzzy));
+    }
+
+    #[test]
+    fn test_card_search_empty_query() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/search?q=__")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Search query must contain a letter or digit.");
+    }
+
+    #[test]
+    fn test_card_search_missing_query() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/search")).dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+}
@@ -0,0 +1,77 @@
+use rocket::{self, get, serde::json::Json};
+
+use crate::endpoints::{api_error::{ApiError, ApiResult}, card_search::DEFAULT_SEARCH_LIMIT};
+use crate::model::{card_text_search::{CardTextSearchResult, search_card_text, text_search_terms}, state::card_data::CardDataSnapshot};
+
+#[get("/cards/text_search?<q>&<edition>&<limit>")]
+pub fn card_text_search(
+    card_data: CardDataSnapshot,
+    q: &str,
+    edition: Option<u8>,
+    limit: Option<usize>
+) -> ApiResult<Vec<CardTextSearchResult>> {
+    if text_search_terms(q).is_empty() {
+        return Err(ApiError::bad_request("Search query must contain a letter or digit."));
+    }
+    Ok(Json(search_card_text(&card_data, q, edition, limit.unwrap_or(DEFAULT_SEARCH_LIMIT))))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::{card_text::TextField, card_text_search::CardTextSearchResult};
+
+    #[test]
+    fn test_card_text_search() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/text_search?q=gains%20a%20curse")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let results: Vec<CardTextSearchResult> = response.into_json().unwrap();
+        let mut names: Vec<&str> = results.iter().map(|result| result.card.name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, vec!["Replace", "Torturer", "Witch"]);
+        let witch = results.iter().find(|result| result.card.name == "Witch").unwrap();
+        assert_eq!(witch.edition, 2);
+        assert_eq!(witch.matched_fields, vec![TextField::Text]);
+    }
+
+    #[test]
+    fn test_card_text_search_edition() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let results: Vec<CardTextSearchResult> = client.get(uri!("/cards/text_search?q=curse%20card&edition=1"))
+            .dispatch()
+            .into_json()
+            .unwrap();
+        let names: Vec<&str> = results.iter().map(|result| result.card.name.as_str()).collect();
+        assert_eq!(names, vec!["Torturer", "Witch"]);
+        assert!(results.iter().all(|result| result.edition == 1));
+    }
+
+    #[test]
+    fn test_card_text_search_limit() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let results: Vec<CardTextSearchResult> = client.get(uri!("/cards/text_search?q=card&limit=1")).dispatch().into_json().unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_card_text_search_empty_query() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/text_search?q=%2B%24")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Search query must contain a letter or digit.");
+    }
+
+    #[test]
+    fn test_card_text_search_missing_query() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/cards/text_search")).dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+}
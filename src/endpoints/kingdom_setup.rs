@@ -0,0 +1,84 @@
+use rocket::{self, get, serde::json::Json};
+
+use crate::endpoints::{api_error::ApiResult, random_kingdom::KingdomFilterQuery};
+use crate::model::{
+    kingdom::SupplyPile,
+    kingdom_generator::{generate_filtered_kingdom_from_seed, KingdomFilter},
+    state::card_data::CardDataSnapshot
+};
+
+/// A kingdom is identified by the seed and filters that `/kingdom/random` returned it for.
+#[get("/kingdom/<seed>/setup/<players>?<filter..>")]
+pub fn kingdom_setup(
+    card_data: CardDataSnapshot,
+    seed: u64,
+    players: u8,
+    filter: KingdomFilterQuery
+) -> ApiResult<Vec<SupplyPile>> {
+    let filter = KingdomFilter::from(filter);
+
+    let kingdom = generate_filtered_kingdom_from_seed(&card_data, &filter, seed)?;
+    Ok(Json(kingdom.supply_for_players(&card_data, players)?))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use crate::endpoints::random_kingdom::RandomKingdom;
+    use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::kingdom::SupplyPile;
+
+    #[test]
+    fn test_kingdom_setup() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let random_kingdom: RandomKingdom = client.get(uri!("/kingdom/random?seed=42")).dispatch().into_json().unwrap();
+        let response = client.get(uri!("/kingdom/42/setup/3")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let supply: Vec<SupplyPile> = response.into_json().unwrap();
+        let card_ids: Vec<u16> = supply.iter().map(|pile| pile.card_id).collect();
+        let expected_card_ids: Vec<u16> = random_kingdom.cards.iter().map(|card| card.id).collect();
+        assert_eq!(card_ids, expected_card_ids);
+
+        let copper = supply.iter().find(|pile| pile.name == "Copper").unwrap();
+        assert_eq!(copper.count, 39);
+        let curse = supply.iter().find(|pile| pile.name == "Curse").unwrap();
+        assert_eq!(curse.count, 20);
+    }
+
+    #[test]
+    fn test_kingdom_setup_with_filters() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let random_kingdom: RandomKingdom = client
+            .get(uri!("/kingdom/random?seed=42&require_tag=TrashFromHand&exclude_tag=CurseAttack"))
+            .dispatch()
+            .into_json()
+            .unwrap();
+        let supply: Vec<SupplyPile> = client
+            .get(uri!("/kingdom/42/setup/4?require_tag=TrashFromHand&exclude_tag=CurseAttack"))
+            .dispatch()
+            .into_json()
+            .unwrap();
+        let card_ids: Vec<u16> = supply.iter().map(|pile| pile.card_id).collect();
+        assert_eq!(card_ids[..10], random_kingdom.kingdom.supply_card_ids[..]);
+    }
+
+    #[test]
+    fn test_kingdom_setup_invalid_player_count() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/42/setup/7")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Player count must be between 2 and 6, got 7.");
+    }
+
+    #[test]
+    fn test_kingdom_setup_impossible_filter() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/42/setup/2?sets=9")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+}
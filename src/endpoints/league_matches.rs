@@ -0,0 +1,62 @@
+use rocket::{self, get, State, serde::json::Json};
+
+use crate::model::{league::league_match::Match, state::league_data::LeagueData};
+
+#[get("/league/matches?<season_id>")]
+pub fn league_matches(league_data: &State<LeagueData>, season_id: Option<u16>) -> Json<Vec<Match>> {
+    let league = league_data.league.lock().unwrap();
+    Json(match season_id {
+        Some(season_id) => league.season_matches(season_id).cloned().collect(),
+        None => league.matches.clone(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use crate::{test_app, test_app_with_league};
+    use crate::model::{kingdom::Kingdom, league::{league_match::{Match, Participant}, league_record::League}};
+
+    fn test_league() -> League {
+        let mut league = League::default();
+        let spring = league.add_season("Spring");
+        let summer = league.add_season("Summer");
+        for season_id in [spring, summer, summer] {
+            league.add_match(
+                season_id,
+                Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] },
+                vec![
+                    Participant { player_name: "A".to_string(), victory_points: 30, turns: 15 },
+                    Participant { player_name: "B".to_string(), victory_points: 20, turns: 15 }
+                ]
+            );
+        }
+        league
+    }
+
+    #[test]
+    fn test_league_matches() {
+        let client = Client::tracked(test_app_with_league("league_matches", test_league())).expect("valid rocket instance");
+        let response = client.get(uri!("/league/matches")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_json::<Vec<Match>>().unwrap(), test_league().matches);
+    }
+
+    #[test]
+    fn test_league_matches_for_season() {
+        let client = Client::tracked(test_app_with_league("league_matches_for_season", test_league())).expect("valid rocket instance");
+        let matches: Vec<Match> = client.get(uri!("/league/matches?season_id=2")).dispatch().into_json().unwrap();
+        let ids: Vec<u32> = matches.iter().map(|played| played.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_league_matches_empty() {
+        let client = Client::tracked(test_app("league_matches_empty")).expect("valid rocket instance");
+        let response = client.get(uri!("/league/matches")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "[]");
+    }
+}
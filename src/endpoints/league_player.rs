@@ -0,0 +1,89 @@
+use rocket::{self, get, State, serde::json::Json};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::endpoints::api_error::{ApiError, ApiResult};
+use crate::model::{
+    league::{
+        league_match::Match,
+        player::Player,
+        rating::{RatingEngine, RatingPoint},
+        standing::{Standing, build_standings}
+    },
+    state::league_data::LeagueData
+};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PlayerSummary {
+    pub player: Player,
+    pub standing: Option<Standing>,
+    pub ratings: Vec<RatingPoint>,
+    pub matches: Vec<Match>,
+}
+
+#[get("/league/player/<name>")]
+pub fn league_player(league_data: &State<LeagueData>, name: &str) -> ApiResult<PlayerSummary> {
+    let league = league_data.league.lock().unwrap();
+    let player = league.player(name)
+        .ok_or_else(|| ApiError::not_found(format!("Unknown player {}.", name)))?
+        .clone();
+
+    let matches: Vec<Match> = league.player_matches(name).cloned().collect();
+    let standing = build_standings(&matches).into_iter().find(|standing| standing.player_name == name);
+    let ratings = RatingEngine::default().rate_matches(&league.matches).into_iter()
+        .find(|history| history.player_name == name)
+        .map(|history| history.history)
+        .unwrap_or_default();
+
+    Ok(Json(PlayerSummary { player, standing, ratings, matches }))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use super::PlayerSummary;
+    use crate::test_app_with_league;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::{kingdom::Kingdom, league::{league_match::Participant, league_record::League}};
+
+    fn test_league() -> League {
+        let mut league = League::default();
+        let season_id = league.add_season("Spring");
+        for (winner, loser) in [("A", "B"), ("B", "C")] {
+            league.add_match(
+                season_id,
+                Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] },
+                vec![
+                    Participant { player_name: winner.to_string(), victory_points: 30, turns: 15 },
+                    Participant { player_name: loser.to_string(), victory_points: 20, turns: 15 }
+                ]
+            );
+        }
+        league
+    }
+
+    #[test]
+    fn test_league_player() {
+        let client = Client::tracked(test_app_with_league("league_player", test_league())).expect("valid rocket instance");
+        let response = client.get(uri!(super::league_player("B"))).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let summary: PlayerSummary = response.into_json().unwrap();
+        assert_eq!(summary.player.name, "B");
+        assert_eq!(summary.matches.len(), 2);
+        assert_eq!(summary.standing.unwrap().wins, 1);
+        assert_eq!(summary.ratings.len(), 2);
+        assert_eq!(summary.ratings[0].rating, 1484.0);
+    }
+
+    #[test]
+    fn test_league_player_unknown_player() {
+        let client = Client::tracked(test_app_with_league("league_player_unknown_player", test_league())).expect("valid rocket instance");
+        let response = client.get(uri!(super::league_player("Z"))).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Unknown player Z.");
+    }
+}
@@ -0,0 +1,59 @@
+use rocket::{self, get, State, serde::json::Json};
+
+use crate::model::{league::standing::{Standing, build_standings}, state::league_data::LeagueData};
+
+#[get("/league/standings?<season_id>")]
+pub fn league_standings(league_data: &State<LeagueData>, season_id: Option<u16>) -> Json<Vec<Standing>> {
+    let league = league_data.league.lock().unwrap();
+    Json(match season_id {
+        Some(season_id) => league.season_standings(season_id),
+        None => build_standings(&league.matches),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use crate::test_app_with_league;
+    use crate::model::{kingdom::Kingdom, league::{league_match::Participant, league_record::League, standing::Standing}};
+
+    fn test_league() -> League {
+        let mut league = League::default();
+        let spring = league.add_season("Spring");
+        let summer = league.add_season("Summer");
+        for (season_id, winner, loser) in [(spring, "A", "B"), (summer, "B", "A"), (summer, "B", "C")] {
+            league.add_match(
+                season_id,
+                Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] },
+                vec![
+                    Participant { player_name: winner.to_string(), victory_points: 30, turns: 15 },
+                    Participant { player_name: loser.to_string(), victory_points: 20, turns: 15 }
+                ]
+            );
+        }
+        league
+    }
+
+    #[test]
+    fn test_league_standings() {
+        let client = Client::tracked(test_app_with_league("league_standings", test_league())).expect("valid rocket instance");
+        let response = client.get(uri!("/league/standings")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let standings: Vec<Standing> = response.into_json().unwrap();
+        let names: Vec<&str> = standings.iter().map(|standing| standing.player_name.as_str()).collect();
+        assert_eq!(names, vec!["B", "A", "C"]);
+        assert_eq!(standings[0].wins, 2);
+    }
+
+    #[test]
+    fn test_league_standings_for_season() {
+        let client = Client::tracked(test_app_with_league("league_standings_for_season", test_league())).expect("valid rocket instance");
+        let standings: Vec<Standing> = client.get(uri!("/league/standings?season_id=1")).dispatch().into_json().unwrap();
+        assert_eq!(standings.len(), 2);
+        assert_eq!(standings[0].player_name, "A");
+    }
+}
@@ -1,5 +1,20 @@
+pub mod api_error;
 pub mod card_art;
 pub mod card_art_from_id;
 pub mod card_json_from_id;
 pub mod card_json_from_name;
+pub mod card_list;
+pub mod card_search;
+pub mod card_text_search;
 pub mod health;
+pub mod kingdom_setup;
+pub mod random_kingdom;
+pub mod set_json;
+pub mod set_files;
+pub mod scraped_file;
+pub mod reload_card_data;
+pub mod add_league_season;
+pub mod submit_league_match;
+pub mod league_matches;
+pub mod league_standings;
+pub mod league_player;
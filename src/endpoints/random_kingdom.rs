@@ -0,0 +1,299 @@
+use rand::Rng;
+use rocket::{self, get, FromForm, form::{self, FromFormField, ValueField}, serde::json::Json};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::endpoints::api_error::ApiResult;
+use crate::model::{
+    card::Card,
+    card_tag::CardTag,
+    kingdom::Kingdom,
+    landscape::Landscape,
+    kingdom_generator::{generate_filtered_kingdom_from_seed, KingdomFilter},
+    owned_editions::OwnedEditions,
+    state::card_data::CardDataSnapshot
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RandomKingdom {
+    pub seed: u64,
+    pub kingdom: Kingdom,
+    pub cards: Vec<Card>,
+    pub landscapes: Vec<Landscape>,
+}
+
+/// Comma separated set ids, `sets=1,2`. Missing sets means every set.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SetIds(pub Vec<u8>);
+
+#[rocket::async_trait]
+impl<'v> FromFormField<'v> for SetIds {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        match field.value.split(',').map(|id| id.trim().parse::<u8>()).collect() {
+            Ok(set_ids) => Ok(SetIds(set_ids)),
+            Err(_) => Err(form::Error::validation("sets must be comma separated set ids"))?,
+        }
+    }
+
+    fn default() -> Option<Self> {
+        Some(SetIds(vec![]))
+    }
+}
+
+/// The editions a group owns of one set, written `1:2` for Dominion 2nd edition or `2:1,2` for both Intrigue editions.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SetEditions {
+    pub set_id: u8,
+    pub editions: Vec<u8>,
+}
+
+#[rocket::async_trait]
+impl<'v> FromFormField<'v> for SetEditions {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        let parsed = field.value.split_once(':').and_then(|(set_id, editions)| {
+            let set_id = set_id.trim().parse::<u8>().ok()?;
+            let editions = editions.split(',').map(|edition| edition.trim().parse::<u8>()).collect::<Result<Vec<u8>, _>>().ok()?;
+            Some(SetEditions { set_id, editions })
+        });
+        match parsed {
+            Some(set_editions) => Ok(set_editions),
+            None => Err(form::Error::validation("owned must be a set id then comma separated editions, such as 1:2"))?,
+        }
+    }
+}
+
+impl From<Vec<SetEditions>> for OwnedEditions {
+    fn from(set_editions: Vec<SetEditions>) -> Self {
+        let mut owned_editions = OwnedEditions::default();
+        for set_edition in set_editions {
+            for edition in set_edition.editions {
+                owned_editions.add(set_edition.set_id, edition);
+            }
+        }
+        owned_editions
+    }
+}
+
+/// The filters shared by every route that builds a kingdom from a seed.
+#[derive(Debug, Clone, FromForm)]
+pub struct KingdomFilterQuery {
+    pub sets: SetIds,
+    pub require_tag: Vec<CardTag>,
+    pub exclude_tag: Vec<CardTag>,
+    pub min_coins: Option<u8>,
+    pub max_coins: Option<u8>,
+    pub exclude_potion: bool,
+    pub exclude_debt: bool,
+    pub owned: Vec<SetEditions>,
+    pub synergy_attempts: Option<usize>,
+}
+
+impl From<KingdomFilterQuery> for KingdomFilter {
+    fn from(query: KingdomFilterQuery) -> Self {
+        KingdomFilter {
+            set_ids: query.sets.0,
+            require_tags: query.require_tag,
+            exclude_tags: query.exclude_tag,
+            min_coins: query.min_coins,
+            max_coins: query.max_coins,
+            exclude_potion: query.exclude_potion,
+            exclude_debt: query.exclude_debt,
+            owned_editions: query.owned.into(),
+            synergy_attempts: query.synergy_attempts,
+        }
+    }
+}
+
+#[get("/kingdom/random?<seed>&<filter..>")]
+pub fn random_kingdom(
+    card_data: CardDataSnapshot,
+    seed: Option<u64>,
+    filter: KingdomFilterQuery
+) -> ApiResult<RandomKingdom> {
+    let seed = seed.unwrap_or_else(|| rand::thread_rng().gen());
+    let filter = KingdomFilter::from(filter);
+
+    let kingdom = generate_filtered_kingdom_from_seed(&card_data, &filter, seed)?;
+    let cards = kingdom.supply_card_ids.iter()
+        .chain(kingdom.basic_card_ids.iter())
+        .map(|id| card_data.id_card_map[id].clone())
+        .collect();
+    let landscapes = kingdom.landscape_ids.iter()
+        .map(|id| card_data.id_landscape_map[id].clone())
+        .collect();
+    Ok(Json(RandomKingdom { seed, kingdom, cards, landscapes }))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use super::RandomKingdom;
+    use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::{card_tag::CardTag, kingdom_score::score_kingdom, state::card_data::build_card_data};
+
+    #[test]
+    fn test_random_kingdom() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let random_kingdom: RandomKingdom = response.into_json().unwrap();
+        assert_eq!(random_kingdom.kingdom.supply_card_ids.len(), 10);
+        assert_eq!(random_kingdom.cards.len(), 17);
+        assert_eq!(random_kingdom.cards[0].id, random_kingdom.kingdom.supply_card_ids[0]);
+        assert_eq!(random_kingdom.cards[10].id, random_kingdom.kingdom.basic_card_ids[0]);
+        assert_eq!(random_kingdom.landscapes.len(), random_kingdom.kingdom.landscape_ids.len());
+        for (landscape, id) in random_kingdom.landscapes.iter().zip(random_kingdom.kingdom.landscape_ids.iter()) {
+            assert_eq!(landscape.id, *id);
+        }
+    }
+
+    #[test]
+    fn test_random_kingdom_seed() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let first: RandomKingdom = client.get(uri!("/kingdom/random?seed=42")).dispatch().into_json().unwrap();
+        let second: RandomKingdom = client.get(uri!("/kingdom/random?seed=42")).dispatch().into_json().unwrap();
+        assert_eq!(first.seed, 42);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_random_kingdom_returned_seed_reproduces_kingdom() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let first: RandomKingdom = client.get(uri!("/kingdom/random")).dispatch().into_json().unwrap();
+        let second: RandomKingdom = client
+            .get(format!("/kingdom/random?seed={}", first.seed))
+            .dispatch()
+            .into_json()
+            .unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_random_kingdom_sets() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?sets=1,2&seed=42")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let random_kingdom: RandomKingdom = response.into_json().unwrap();
+        for card in random_kingdom.cards.iter().take(10) {
+            assert!([1, 2].contains(&card.set_id));
+        }
+    }
+
+    #[test]
+    fn test_random_kingdom_owned_editions() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        for seed in 0..10 {
+            let response = client.get(format!("/kingdom/random?owned=1:2&seed={}", seed)).dispatch();
+            assert_eq!(response.status(), Status::Ok);
+
+            let random_kingdom: RandomKingdom = response.into_json().unwrap();
+            for card in random_kingdom.cards.iter() {
+                assert!(card.editions.contains(&2));
+                assert_ne!(card.name, "Adventurer");
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_kingdom_bad_sets() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?sets=1,dominion")).dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn test_random_kingdom_tags() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client
+            .get(uri!("/kingdom/random?seed=42&require_tag=TrashFromHand&exclude_tag=CurseAttack"))
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let random_kingdom: RandomKingdom = response.into_json().unwrap();
+        let supply_cards = &random_kingdom.cards[..10];
+        assert!(supply_cards.iter().any(|card| card.card_tags.contains(&CardTag::TrashFromHand)));
+        assert!(!supply_cards.iter().any(|card| card.card_tags.contains(&CardTag::CurseAttack)));
+    }
+
+    #[test]
+    fn test_random_kingdom_coins() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?seed=42&min_coins=3&max_coins=5&exclude_potion=true")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let random_kingdom: RandomKingdom = response.into_json().unwrap();
+        for card in random_kingdom.cards.iter().take(10) {
+            assert!((3..=5).contains(&card.cost.coins));
+        }
+    }
+
+    #[test]
+    fn test_random_kingdom_sorted_by_cost() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let random_kingdom: RandomKingdom = client.get(uri!("/kingdom/random?seed=42")).dispatch().into_json().unwrap();
+        let costs: Vec<u8> = random_kingdom.cards.iter().take(10).map(|card| card.cost.coins).collect();
+        let mut sorted = costs.clone();
+        sorted.sort_unstable();
+        assert_eq!(costs, sorted);
+    }
+
+    #[test]
+    fn test_random_kingdom_unknown_tag() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?require_tag=NotATag")).dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn test_random_kingdom_synergy_attempts() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let card_data = build_card_data();
+        let interaction_score = |uri: &str| {
+            let random_kingdom: RandomKingdom = client.get(uri).dispatch().into_json().unwrap();
+            score_kingdom(&random_kingdom.kingdom, &card_data).interaction_score
+        };
+        for seed in 0..5 {
+            let single = interaction_score(&format!("/kingdom/random?seed={}", seed));
+            let weighted = interaction_score(&format!("/kingdom/random?seed={}&synergy_attempts=20", seed));
+            assert!(weighted >= single);
+        }
+
+        let first: RandomKingdom = client.get(uri!("/kingdom/random?seed=3&synergy_attempts=20")).dispatch().into_json().unwrap();
+        let second: RandomKingdom = client.get(uri!("/kingdom/random?seed=3&synergy_attempts=20")).dispatch().into_json().unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_random_kingdom_invalid_synergy_attempts() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?synergy_attempts=0")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(
+            response.into_json::<ApiErrorBody>().unwrap().message,
+            "Synergy attempts must be between 1 and 100, got 0.");
+    }
+
+    #[test]
+    fn test_random_kingdom_impossible_filter() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?sets=1&min_coins=6")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(
+            response.into_json::<ApiErrorBody>().unwrap().message,
+            "Not enough cards to build a kingdom, 10 required but only 2 could be used.");
+    }
+
+    #[test]
+    fn test_random_kingdom_set_without_card_data() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!("/kingdom/random?sets=9")).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "There is no card data for set ids 9.");
+    }
+}
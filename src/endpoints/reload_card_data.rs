@@ -0,0 +1,214 @@
+use rocket::{self, post, State, http::Status, request::{FromRequest, Outcome, Request}, serde::json::Json};
+use rocket::serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::endpoints::api_error::{ApiError, ApiResult};
+use crate::model::{
+    data_loaders::set_data_loader::load_set_vec,
+    state::{card_data::CardDataState, scraped_manifest::ScrapedManifestState, set_data::{SetDataState, build_set_data_from_sets}}
+};
+
+pub const ADMIN_TOKEN_HEADER: &str = "X-Admin-Token";
+
+/// Managed only when `admin_token` is configured, without it the admin routes are not found.
+pub struct AdminToken(pub String);
+
+/// A request carrying the configured admin token.
+pub struct Admin;
+
+/// Compares the hashes of the tokens so the time taken says nothing about how much of a guess was right.
+fn tokens_match(header: &str, token: &str) -> bool {
+    Sha256::digest(header.as_bytes()).iter()
+        .zip(Sha256::digest(token.as_bytes()).iter())
+        .fold(0, |difference, (a, b)| difference | (a ^ b)) == 0
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Admin {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, ()> {
+        let Some(AdminToken(token)) = request.rocket().state::<AdminToken>() else {
+            return Outcome::Error((Status::NotFound, ()));
+        };
+        match request.headers().get_one(ADMIN_TOKEN_HEADER) {
+            Some(header) if tokens_match(header, token) => Outcome::Success(Admin),
+            _ => Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CardDataReload {
+    pub cards: usize,
+    pub landscapes: usize,
+    pub sets: usize,
+    pub scraped_files: usize,
+}
+
+/// Reloads the cards, landscapes and sets from the data directory and the manifest of a finished scrape.
+/// Nothing is replaced if any record is malformed.
+#[post("/admin/reload_cards")]
+pub fn reload_card_data(
+    _admin: Admin,
+    card_data_state: &State<CardDataState>,
+    set_data_state: &State<SetDataState>,
+    scraped_manifest_state: &State<ScrapedManifestState>
+) -> ApiResult<CardDataReload> {
+    // the card files are found from the sets, both are built from one read of the set file so they always agree
+    let sets = load_set_vec(&card_data_state.data_dir)?;
+    let scraped_manifest = scraped_manifest_state.load()
+        .map_err(|error| ApiError::internal(format!("Could not load the scrape manifest. {}", error)))?;
+    let card_data = card_data_state.reload(&sets)?;
+    let set_data = set_data_state.replace(build_set_data_from_sets(sets));
+    let scraped_manifest = scraped_manifest_state.replace(scraped_manifest);
+    Ok(Json(CardDataReload {
+        cards: card_data.id_card_map.len(),
+        landscapes: card_data.id_landscape_map.len(),
+        sets: set_data.sets.len(),
+        scraped_files: scraped_manifest.manifest.files.len()
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs::{create_dir_all, remove_dir_all, write}, path::PathBuf};
+
+    use rocket::local::blocking::Client;
+    use rocket::http::{Header, Status};
+    use rocket::serde::json::serde_json;
+    use rocket::uri;
+
+    use super::*;
+    use crate::{launch_app, test_app_folder, test_app_with_data_dir};
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::{
+        card::Card,
+        data_loaders::{
+            card_data_loader::{BASE_CARD_FILE_NAME, get_all_card_vec, get_base_card_vec, get_dominion_card_vec, set_card_file_name},
+            data_dir::DataDir,
+            landscape_data_loader::get_landscape_vec,
+            set_data_loader::{SET_FILE_NAME, get_set_vec}
+        },
+        scrapers::scrape_engine::{MANIFEST_FILE_NAME, Manifest, ManifestEntry},
+        set::Set
+    };
+
+    fn test_dir(name: &str) -> PathBuf {
+        let folder = temp_dir().join(format!("dominion_reload_cards_{}_{}", name, std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    #[test]
+    fn test_reload_card_data() {
+        let folder = test_dir("reload");
+        let client = Client::tracked(test_app_with_data_dir("reload_cards", DataDir::new(&folder), Some("secret")))
+            .expect("valid rocket instance");
+        let mut cards = get_base_card_vec();
+        cards[0].name = "Fixed Copper".to_string();
+        write(folder.join(BASE_CARD_FILE_NAME), serde_json::to_string(&cards).unwrap()).unwrap();
+        let mut manifest = Manifest::default();
+        manifest.files.insert("cards/Copper_1.jpeg".to_string(), ManifestEntry {
+            url: "http://a".to_string(),
+            sha256: "00".to_string(),
+            mime_type: Some("image/jpeg".to_string())
+        });
+        manifest.save(&test_app_folder("reload_cards").join("scraped_data").join(MANIFEST_FILE_NAME)).unwrap();
+
+        let response = client.post(uri!(super::reload_card_data)).header(Header::new(ADMIN_TOKEN_HEADER, "secret")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_json::<CardDataReload>().unwrap(), CardDataReload {
+            cards: get_all_card_vec().len(),
+            landscapes: get_landscape_vec().len(),
+            sets: get_set_vec().len(),
+            scraped_files: 1
+        });
+
+        let card: Card = client.get("/card_json/0").dispatch().into_json().unwrap();
+        assert_eq!(card.name, "Fixed Copper");
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_reload_set_data() {
+        let folder = test_dir("sets");
+        let client = Client::tracked(test_app_with_data_dir("reload_sets", DataDir::new(&folder), Some("secret")))
+            .expect("valid rocket instance");
+        let mut sets = get_set_vec();
+        sets[0].name = "Fixed Dominion".to_string();
+        write(folder.join(SET_FILE_NAME), serde_json::to_string(&sets).unwrap()).unwrap();
+        // renaming the set renames its card file, keep the cards where the reload will look for them
+        write(folder.join(set_card_file_name(&sets[0])), serde_json::to_string(&get_dominion_card_vec()).unwrap()).unwrap();
+
+        let response = client.post(uri!(super::reload_card_data)).header(Header::new(ADMIN_TOKEN_HEADER, "secret")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        let set: Set = client.get("/set/1").dispatch().into_json().unwrap();
+        assert_eq!(set.name, "Fixed Dominion");
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_reload_malformed_set_data_keeps_sets() {
+        let folder = test_dir("malformed_sets");
+        let client = Client::tracked(test_app_with_data_dir("reload_sets_malformed", DataDir::new(&folder), Some("secret")))
+            .expect("valid rocket instance");
+        let mut cards = get_base_card_vec();
+        cards[0].name = "Fixed Copper".to_string();
+        write(folder.join(BASE_CARD_FILE_NAME), serde_json::to_string(&cards).unwrap()).unwrap();
+        write(folder.join(SET_FILE_NAME), "[{}]").unwrap();
+
+        let response = client.post(uri!(super::reload_card_data)).header(Header::new(ADMIN_TOKEN_HEADER, "secret")).dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+        let set: Set = client.get("/set/1").dispatch().into_json().unwrap();
+        assert_eq!(set.name, "Dominion");
+        let card: Card = client.get("/card_json/0").dispatch().into_json().unwrap();
+        assert_eq!(card.name, "Copper");
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_reload_card_data_malformed_records() {
+        let folder = test_dir("malformed");
+        let client = Client::tracked(test_app_with_data_dir("reload_cards_malformed", DataDir::new(&folder), Some("secret")))
+            .expect("valid rocket instance");
+        write(folder.join(BASE_CARD_FILE_NAME), "[{}]").unwrap();
+
+        let response = client.post(uri!(super::reload_card_data)).header(Header::new(ADMIN_TOKEN_HEADER, "secret")).dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+        assert_eq!(
+            response.into_json::<ApiErrorBody>().unwrap().message,
+            "Could not load the card data. 1 malformed records.\nbasic_cards.json record 0: missing field `id`");
+
+        let card: Card = client.get("/card_json/0").dispatch().into_json().unwrap();
+        assert_eq!(card.name, "Copper");
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_reload_card_data_wrong_token() {
+        let client = Client::tracked(test_app_with_data_dir("reload_cards_wrong_token", DataDir::embedded(), Some("secret")))
+            .expect("valid rocket instance");
+        let response = client.post(uri!(super::reload_card_data)).header(Header::new(ADMIN_TOKEN_HEADER, "guess")).dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+        let response = client.post(uri!(super::reload_card_data)).dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+    }
+
+    #[test]
+    fn test_tokens_match() {
+        assert!(tokens_match("secret", "secret"));
+        assert!(!tokens_match("secre", "secret"));
+        assert!(!tokens_match("", "secret"));
+        assert!(!tokens_match("Secret", "secret"));
+    }
+
+    #[test]
+    fn test_reload_card_data_without_admin_token() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.post(uri!(super::reload_card_data)).header(Header::new(ADMIN_TOKEN_HEADER, "")).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
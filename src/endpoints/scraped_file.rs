@@ -0,0 +1,50 @@
+use std::{io, path::Path};
+
+use rocket::{fs::NamedFile, http::ContentType};
+
+use crate::model::state::scraped_manifest::ScrapedManifest;
+
+/// The MIME type the scraper recorded for a file, files scraped before types were recorded go by their extension.
+fn scraped_file_content_type(manifest: &ScrapedManifest, path: &Path) -> ContentType {
+    manifest.mime_type(path)
+        .and_then(ContentType::parse_flexible)
+        .or_else(|| path.extension().and_then(|extension| extension.to_str()).and_then(ContentType::from_extension))
+        .unwrap_or(ContentType::Binary)
+}
+
+/// Opens a file from the scraped data with the content type the manifest recorded for it.
+pub async fn open_scraped_file(manifest: &ScrapedManifest, path: &Path) -> io::Result<(ContentType, NamedFile)> {
+    let content_type = scraped_file_content_type(manifest, path);
+    Ok((content_type, NamedFile::open(path).await?))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use rocket::http::ContentType;
+
+    use crate::model::{scrapers::scrape_engine::{Manifest, ManifestEntry}, state::scraped_manifest::ScrapedManifest};
+    use super::scraped_file_content_type;
+
+    #[test]
+    fn test_scraped_file_content_type() {
+        let mut manifest = Manifest::default();
+        manifest.files.insert("cards/Chapel_1.jpeg".to_string(), ManifestEntry {
+            url: "http://a".to_string(),
+            sha256: "00".to_string(),
+            mime_type: Some("image/png".to_string())
+        });
+        manifest.files.insert("cards/Moat_1.jpeg".to_string(), ManifestEntry {
+            url: "http://b".to_string(),
+            sha256: "00".to_string(),
+            mime_type: None
+        });
+        let manifest = ScrapedManifest { manifest_path: PathBuf::from("scraped_data/manifest.json"), manifest };
+
+        assert_eq!(scraped_file_content_type(&manifest, Path::new("scraped_data/cards/Chapel_1.jpeg")), ContentType::PNG);
+        assert_eq!(scraped_file_content_type(&manifest, Path::new("scraped_data/cards/Moat_1.jpeg")), ContentType::JPEG);
+        assert_eq!(scraped_file_content_type(&manifest, Path::new("scraped_data/Dominion_1/rules.pdf")), ContentType::PDF);
+        assert_eq!(scraped_file_content_type(&manifest, Path::new("scraped_data/notes")), ContentType::Binary);
+    }
+}
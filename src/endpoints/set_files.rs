@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use rocket::{self, get, fs::NamedFile, http::ContentType};
+
+use crate::endpoints::{api_error::ApiError, scraped_file::open_scraped_file};
+use crate::model::{
+    set::{Set, SetFilePaths},
+    state::{scraped_manifest::{ScrapedManifest, ScrapedManifestSnapshot}, set_data::{SetData, SetDataSnapshot}}
+};
+
+fn find_set_edition(set_data: &SetData, id: u8, edition: u8) -> Result<&Set, ApiError> {
+    let set = set_data.id_set_map.get(&id)
+        .ok_or_else(|| ApiError::not_found(format!("Unknown set id {}.", id)))?;
+    if !set.editions.contains(&edition) {
+        return Err(ApiError::not_found(format!("Set {} has no edition {}.", set.name, edition)));
+    }
+    Ok(set)
+}
+
+/// The content type is the one the scraper recorded, png for images and pdf for rule books.
+async fn open_set_file(manifest: &ScrapedManifest, set: &Set, edition: u8, file_kind: &str, path: String) -> Result<(ContentType, NamedFile), ApiError> {
+    if !Path::new(&path).exists() {
+        return Err(ApiError::not_found(format!("No {} has been scraped for {} edition {}.", file_kind, set.name, edition)));
+    }
+    open_scraped_file(manifest, Path::new(&path)).await.map_err(|error| ApiError::internal(error.to_string()))
+}
+
+#[get("/set/<id>/<edition>/cover")]
+pub async fn set_cover(set_data: SetDataSnapshot, manifest: ScrapedManifestSnapshot, id: u8, edition: u8) -> Result<(ContentType, NamedFile), ApiError> {
+    let set = find_set_edition(&set_data, id, edition)?;
+    open_set_file(&manifest, set, edition, "cover art", set.get_cover_art_path(edition)).await
+}
+
+#[get("/set/<id>/<edition>/icon")]
+pub async fn set_icon(set_data: SetDataSnapshot, manifest: ScrapedManifestSnapshot, id: u8, edition: u8) -> Result<(ContentType, NamedFile), ApiError> {
+    let set = find_set_edition(&set_data, id, edition)?;
+    open_set_file(&manifest, set, edition, "icon", set.get_icon_path(edition)).await
+}
+
+#[get("/set/<id>/<edition>/rules")]
+pub async fn set_rules(set_data: SetDataSnapshot, manifest: ScrapedManifestSnapshot, id: u8, edition: u8) -> Result<(ContentType, NamedFile), ApiError> {
+    let set = find_set_edition(&set_data, id, edition)?;
+    open_set_file(&manifest, set, edition, "rule book", set.get_rule_book_path(edition)).await
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::read;
+
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+    use rocket::uri;
+
+    use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::{data_loaders::set_data_loader::get_set_vec, set::SetFilePaths};
+
+    #[test]
+    fn test_set_cover() {
+        let dominion = &get_set_vec()[0];
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_cover(1, 2))).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::PNG));
+        assert_eq!(response.into_bytes().unwrap(), read(dominion.get_cover_art_path(2)).unwrap());
+    }
+
+    #[test]
+    fn test_set_icon() {
+        let dominion = &get_set_vec()[0];
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_icon(1, 1))).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::PNG));
+        assert_eq!(response.into_bytes().unwrap(), read(dominion.get_icon_path(1)).unwrap());
+    }
+
+    #[test]
+    fn test_set_rules() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_rules(1, 1))).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::PDF));
+    }
+
+    #[test]
+    fn test_set_rules_not_scraped() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_rules(3, 2))).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "No rule book has been scraped for Seaside edition 2.");
+    }
+
+    #[test]
+    fn test_set_cover_unknown_edition() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_cover(4, 2))).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Set Alchemy has no edition 2.");
+    }
+
+    #[test]
+    fn test_set_icon_unknown_set() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_icon(200, 1))).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Unknown set id 200.");
+    }
+}
@@ -0,0 +1,52 @@
+use rocket::{self, get, serde::json::Json};
+
+use crate::endpoints::api_error::{ApiError, ApiResult};
+use crate::model::{set::Set, state::set_data::SetDataSnapshot};
+
+#[get("/sets")]
+pub fn sets_json(set_data: SetDataSnapshot) -> Json<Vec<Set>> {
+    Json(set_data.sets.clone())
+}
+
+#[get("/set/<id>")]
+pub fn set_json_from_id(set_data: SetDataSnapshot, id: u8) -> ApiResult<Set> {
+    match set_data.id_set_map.get(&id) {
+        Some(set) => Ok(Json(set.clone())),
+        None => Err(ApiError::not_found(format!("Unknown set id {}.", id))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::uri;
+
+    use crate::launch_app;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::{data_loaders::set_data_loader::get_set_vec, set::Set};
+
+    #[test]
+    fn test_sets_json() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::sets_json)).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_json::<Vec<Set>>().unwrap(), get_set_vec());
+    }
+
+    #[test]
+    fn test_set_json_from_id() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_json_from_id(1))).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_json::<Set>().unwrap(), get_set_vec()[0]);
+    }
+
+    #[test]
+    fn test_set_json_from_id_unknown_id() {
+        let client = Client::tracked(launch_app()).expect("valid rocket instance");
+        let response = client.get(uri!(super::set_json_from_id(200))).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.into_json::<ApiErrorBody>().unwrap().message, "Unknown set id 200.");
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+
+use rocket::{self, post, State, http::Status, response::status::Custom, serde::json::Json};
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::endpoints::api_error::ApiError;
+use crate::model::{
+    kingdom::Kingdom,
+    league::league_match::{Match, Participant},
+    state::{card_data::{CardData, CardDataSnapshot}, league_data::LeagueData}
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct MatchSubmission {
+    pub season_id: u16,
+    pub kingdom: Kingdom,
+    pub participants: Vec<Participant>,
+}
+
+fn validate_submission(
+    card_data: &CardData,
+    league_data: &LeagueData,
+    submission: &MatchSubmission
+) -> Result<(), ApiError> {
+    let players = u8::try_from(submission.participants.len()).unwrap_or(u8::MAX);
+    // building the supply checks every card id is known and there is a card count for this many players
+    submission.kingdom.supply_for_players(card_data, players)?;
+    if let Some(id) = submission.kingdom.landscape_ids.iter().find(|id| !card_data.id_landscape_map.contains_key(id)) {
+        return Err(ApiError::bad_request(format!("Unknown landscape id {}.", id)));
+    }
+
+    let mut names = HashSet::new();
+    for participant in submission.participants.iter() {
+        if participant.player_name.trim().is_empty() {
+            return Err(ApiError::bad_request("Player name can not be empty."));
+        }
+        if !names.insert(participant.player_name.as_str()) {
+            return Err(ApiError::bad_request(format!("Player {} is listed more than once.", participant.player_name)));
+        }
+    }
+
+    let league = league_data.league.lock().unwrap();
+    if !league.seasons.iter().any(|season| season.id == submission.season_id) {
+        return Err(ApiError::bad_request(format!("Unknown season id {}.", submission.season_id)));
+    }
+    Ok(())
+}
+
+#[post("/league/match", format = "json", data = "<submission>")]
+pub fn submit_league_match(
+    card_data: CardDataSnapshot,
+    league_data: &State<LeagueData>,
+    submission: Json<MatchSubmission>
+) -> Result<Custom<Json<Match>>, ApiError> {
+    validate_submission(&card_data, league_data, &submission)?;
+
+    let submission = submission.into_inner();
+    let played = league_data
+        .update(|league| {
+            let id = league.add_match(submission.season_id, submission.kingdom, submission.participants);
+            league.matches.iter().find(|played| played.id == id).unwrap().clone()
+        })
+        .map_err(|error| ApiError::internal(error.to_string()))?;
+    Ok(Custom(Status::Created, Json(played)))
+}
+
+#[cfg(test)]
+mod test {
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+    use rocket::uri;
+
+    use crate::test_app;
+    use crate::endpoints::api_error::ApiErrorBody;
+    use crate::model::league::league_match::Match;
+
+    fn add_season(client: &Client) {
+        client.post(uri!("/league/season"))
+            .header(ContentType::JSON)
+            .body("{\"name\":\"Spring\"}")
+            .dispatch();
+    }
+
+    fn submit(client: &Client, body: &str) -> (Status, String) {
+        let response = client.post(uri!("/league/match"))
+            .header(ContentType::JSON)
+            .body(body)
+            .dispatch();
+        let status = response.status();
+        (status, response.into_json::<ApiErrorBody>().map(|body| body.message).unwrap_or_default())
+    }
+
+    #[test]
+    fn test_submit_league_match() {
+        let client = Client::tracked(test_app("submit_league_match")).expect("valid rocket instance");
+        add_season(&client);
+        let response = client.post(uri!("/league/match"))
+            .header(ContentType::JSON)
+            .body("{\"season_id\":1,\"kingdom\":{\"supply_card_ids\":[101,102],\"basic_card_ids\":[0,1]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15},{\"player_name\":\"B\",\"victory_points\":20,\"turns\":15}]}")
+            .dispatch();
+        assert_eq!(response.status(), Status::Created);
+
+        let played: Match = response.into_json().unwrap();
+        assert_eq!(played.id, 1);
+        assert_eq!(played.participants.len(), 2);
+    }
+
+    #[test]
+    fn test_submit_league_match_unknown_card_id() {
+        let client = Client::tracked(test_app("submit_league_match_unknown_card_id")).expect("valid rocket instance");
+        add_season(&client);
+        let (status, body) = submit(&client, "{\"season_id\":1,\"kingdom\":{\"supply_card_ids\":[101,65500],\"basic_card_ids\":[0,1]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15},{\"player_name\":\"B\",\"victory_points\":20,\"turns\":15}]}");
+        assert_eq!(status, Status::BadRequest);
+        assert_eq!(body, "Unknown card id 65500.");
+    }
+
+    #[test]
+    fn test_submit_league_match_unknown_landscape_id() {
+        let client = Client::tracked(test_app("submit_league_match_unknown_landscape_id")).expect("valid rocket instance");
+        add_season(&client);
+        let (status, body) = submit(&client, "{\"season_id\":1,\"kingdom\":{\"supply_card_ids\":[101],\"basic_card_ids\":[0],\"landscape_ids\":[31000,101]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15},{\"player_name\":\"B\",\"victory_points\":20,\"turns\":15}]}");
+        assert_eq!(status, Status::BadRequest);
+        assert_eq!(body, "Unknown landscape id 101.");
+    }
+
+    #[test]
+    fn test_submit_league_match_invalid_player_count() {
+        let client = Client::tracked(test_app("submit_league_match_invalid_player_count")).expect("valid rocket instance");
+        add_season(&client);
+        let (status, body) = submit(&client, "{\"season_id\":1,\"kingdom\":{\"supply_card_ids\":[101],\"basic_card_ids\":[0]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15}]}");
+        assert_eq!(status, Status::BadRequest);
+        assert_eq!(body, "Player count must be between 2 and 6, got 1.");
+    }
+
+    #[test]
+    fn test_submit_league_match_duplicate_player() {
+        let client = Client::tracked(test_app("submit_league_match_duplicate_player")).expect("valid rocket instance");
+        add_season(&client);
+        let (status, body) = submit(&client, "{\"season_id\":1,\"kingdom\":{\"supply_card_ids\":[101],\"basic_card_ids\":[0]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15},{\"player_name\":\"A\",\"victory_points\":20,\"turns\":15}]}");
+        assert_eq!(status, Status::BadRequest);
+        assert_eq!(body, "Player A is listed more than once.");
+    }
+
+    #[test]
+    fn test_submit_league_match_unknown_season() {
+        let client = Client::tracked(test_app("submit_league_match_unknown_season")).expect("valid rocket instance");
+        let (status, body) = submit(&client, "{\"season_id\":4,\"kingdom\":{\"supply_card_ids\":[101],\"basic_card_ids\":[0]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15},{\"player_name\":\"B\",\"victory_points\":20,\"turns\":15}]}");
+        assert_eq!(status, Status::BadRequest);
+        assert_eq!(body, "Unknown season id 4.");
+    }
+
+    #[test]
+    fn test_submit_league_match_malformed_json() {
+        let client = Client::tracked(test_app("submit_league_match_malformed_json")).expect("valid rocket instance");
+        let (status, _) = submit(&client, "{\"season_id\":1}");
+        assert_eq!(status, Status::UnprocessableEntity);
+    }
+}
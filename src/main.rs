@@ -4,28 +4,121 @@ use crate::endpoints::card_art::MountCardArtFileServer;
 pub mod endpoints;
 pub mod model;
 
-use model::state::card_data::build_card_data;
+use model::league::league_store::{LeagueStore, DEFAULT_LEAGUE_STORE_PATH};
+use model::data_loaders::data_dir::DataDir;
+use model::scrapers::scrape_engine::DEFAULT_OUTPUT_DIR;
+use model::state::{
+    card_data::build_card_data_state,
+    league_data::build_league_data,
+    scraped_manifest::build_scraped_manifest_state,
+    set_data::build_set_data_state
+};
 use rocket::{Rocket, Build, build};
+use std::path::Path;
 
-use crate::endpoints::{ 
-    health::health, 
-    card_json_from_id::card_json_from_id, 
+use crate::endpoints::{
+    api_error::default_catcher,
+    health::health,
+    card_json_from_id::card_json_from_id,
     card_json_from_name::card_json_from_name,
-    card_art_from_id::{card_art_from_id, card_art_from_id_with_edition}
+    card_search::card_search,
+    card_text_search::card_text_search,
+    card_list::card_list,
+    set_json::{sets_json, set_json_from_id},
+    set_files::{set_cover, set_icon, set_rules},
+    card_art_from_id::{card_art_from_id, card_art_from_id_with_edition},
+    random_kingdom::random_kingdom,
+    kingdom_setup::kingdom_setup,
+    add_league_season::add_league_season,
+    submit_league_match::submit_league_match,
+    league_matches::league_matches,
+    league_standings::league_standings,
+    league_player::league_player,
+    reload_card_data::{reload_card_data, AdminToken}
  };
 
 #[launch]
 #[mutants::skip]
 fn launch_app() -> Rocket<Build> {
-    build()
-    .manage(build_card_data())
+    let rocket = build();
+    let league_store_path: String = rocket.figment()
+        .extract_inner("league_store_path")
+        .unwrap_or_else(|_| DEFAULT_LEAGUE_STORE_PATH.to_string());
+    let data_dir = match rocket.figment().extract_inner::<String>("data_dir") {
+        Ok(path) => DataDir::new(path),
+        Err(_) => DataDir::embedded(),
+    };
+    mount_app(rocket, LeagueStore::new(league_store_path), data_dir, Path::new(DEFAULT_OUTPUT_DIR))
+}
+
+#[mutants::skip]
+fn mount_app(rocket: Rocket<Build>, league_store: LeagueStore, data_dir: DataDir, scraped_data_dir: &Path) -> Rocket<Build> {
+    let admin_token = rocket.figment().extract_inner::<String>("admin_token").ok();
+    let set_data_state = build_set_data_state(&data_dir);
+    let rocket = match admin_token {
+        Some(admin_token) => rocket.manage(AdminToken(admin_token)),
+        None => rocket,
+    };
+    rocket
+    .manage(build_card_data_state(data_dir))
+    .manage(set_data_state)
+    .manage(build_scraped_manifest_state(scraped_data_dir))
+    .manage(build_league_data(league_store))
     .mount_card_art_file_server()
+    .register("/", catchers![default_catcher])
     .mount("/", routes![
         health,
         card_json_from_id,
         card_json_from_name,
+        card_search,
+        card_text_search,
+        card_list,
+        sets_json,
+        set_json_from_id,
+        set_cover,
+        set_icon,
+        set_rules,
         card_art_from_id,
-        card_art_from_id_with_edition])
+        card_art_from_id_with_edition,
+        random_kingdom,
+        kingdom_setup,
+        add_league_season,
+        submit_league_match,
+        league_matches,
+        league_standings,
+        league_player,
+        reload_card_data])
+}
+
+#[cfg(test)]
+pub(crate) fn test_app_with_league(name: &str, league: model::league::league_record::League) -> Rocket<Build> {
+    let folder = std::env::temp_dir().join(format!("dominion_league_app_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&folder);
+    let league_store = LeagueStore::new(folder.join("league.json"));
+    league_store.save(&league).unwrap();
+    mount_app(build(), league_store, DataDir::embedded(), Path::new(DEFAULT_OUTPUT_DIR))
+}
+
+#[cfg(test)]
+pub(crate) fn test_app_folder(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("dominion_league_app_{}_{}", name, std::process::id()))
+}
+
+/// The scrape manifest is read from `scraped_data` in the app's temp folder rather than the checkout's scraped data.
+#[cfg(test)]
+pub(crate) fn test_app_with_data_dir(name: &str, data_dir: DataDir, admin_token: Option<&str>) -> Rocket<Build> {
+    let folder = test_app_folder(name);
+    let _ = std::fs::remove_dir_all(&folder);
+    let figment = match admin_token {
+        Some(admin_token) => rocket::Config::figment().merge(("admin_token", admin_token)),
+        None => rocket::Config::figment(),
+    };
+    mount_app(rocket::custom(figment), LeagueStore::new(folder.join("league.json")), data_dir, &folder.join("scraped_data"))
+}
+
+#[cfg(test)]
+pub(crate) fn test_app(name: &str) -> Rocket<Build> {
+    test_app_with_league(name, Default::default())
 }
 
 #[cfg(test)]
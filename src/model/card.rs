@@ -3,7 +3,7 @@ use std::{fs::canonicalize, path::PathBuf};
 use regex::Regex;
 use rocket::serde::{Deserialize, Serialize};
 
-use super::{card_type::CardType, card_tag::CardTag};
+use super::{card_type::CardType, card_tag::CardTag, card_relationships::CardRelationships, card_text::CardText, cost::Cost};
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(crate = "rocket::serde")]
@@ -13,6 +13,7 @@ pub struct Card {
     pub supply_card: bool,
     pub basic_card: bool,
     pub card_counts: [u8; 5],// for games with players 2,3,4,5,6  
+    pub cost: Cost,
     pub set_id: u8,
     pub editions: Vec<u8>,
     pub card_tags: Vec<CardTag>,
@@ -20,7 +21,12 @@ pub struct Card {
     pub kingdom_synergies: Vec<Vec<CardTag>>,
     pub kingdom_anti_synergies: Vec<Vec<CardTag>>,
     pub card_types: Vec<CardType>,
-    pub art_url: Vec<String>
+    pub art_url: Vec<String>,
+    #[serde(default)]
+    pub relationships: CardRelationships,
+    // one entry per edition that has its wording recorded
+    #[serde(default)]
+    pub texts: Vec<CardText>,
 }
 
 pub trait CardFilepath {
@@ -63,6 +69,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],  
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -70,7 +77,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = card_one.clone();
         card_one.id = 20;
@@ -89,6 +98,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],  
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -96,7 +106,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let mut card_two = Card { 
             id: 2,
@@ -104,6 +116,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 2,
             editions: [2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -111,7 +124,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         card_two.clone_from(&card_one);
         assert_eq!(card_two.id, 1);
@@ -126,6 +141,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -133,7 +149,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -141,6 +159,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -148,7 +167,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one == card_two);
     }
@@ -162,6 +183,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -169,7 +191,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 2,
@@ -177,6 +201,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -184,7 +209,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -198,6 +225,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -205,7 +233,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -213,6 +243,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -220,7 +251,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -234,6 +267,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -241,7 +275,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -249,6 +285,7 @@ mod test {
             supply_card: false,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -256,7 +293,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -270,6 +309,7 @@ mod test {
             supply_card: false,
             basic_card: true,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -277,7 +317,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -285,6 +327,7 @@ mod test {
             supply_card: false,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -292,7 +335,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -306,6 +351,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 1,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -313,7 +359,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -321,6 +369,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 12, 12],
+            cost: Cost::from_coins(4),
             set_id: 1,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -328,7 +377,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -342,6 +393,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -349,7 +401,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -357,6 +411,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 1,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -364,7 +419,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -378,6 +435,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -385,7 +443,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -393,6 +453,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -400,7 +461,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -414,6 +477,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::WillGainBuy].to_vec(),
@@ -421,7 +485,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -429,6 +495,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -436,7 +503,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -450,6 +519,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -457,7 +527,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -465,6 +537,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -472,7 +545,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -486,6 +561,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -493,7 +569,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -501,6 +579,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -508,7 +587,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -522,6 +603,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -529,7 +611,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -537,6 +621,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -544,7 +629,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -558,6 +645,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -565,7 +653,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -573,6 +663,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -580,7 +671,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Reaction, CardType::Action ,CardType::Victory].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -594,6 +687,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -601,7 +695,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image4.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image4.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let card_two = Card { 
             id: 1,
@@ -609,6 +705,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -616,7 +713,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(card_one != card_two);
     }
@@ -630,6 +729,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -637,13 +737,15 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let json = serde_json::to_string(&card).unwrap();
         
         assert_eq!(
             json,
-            "{\"id\":1,\"name\":\"test name\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"set_id\":0,\"editions\":[1,2],\"card_tags\":[\"Costs4\"],\"kingdom_requirements\":[[\"AddCardToTopOfDeck\"]],\"kingdom_synergies\":[[\"CanReplaceAction\"]],\"kingdom_anti_synergies\":[[\"WillReplaceAction\"]],\"card_types\":[\"Action\",\"Attack\",\"Curse\"],\"art_url\":[\"www.image1.com\",\"www.image2.com\"]}");
+            "{\"id\":1,\"name\":\"test name\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"cost\":{\"coins\":4,\"potion\":false,\"debt\":0,\"modifiers\":[]},\"set_id\":0,\"editions\":[1,2],\"card_tags\":[\"Costs4\"],\"kingdom_requirements\":[[\"AddCardToTopOfDeck\"]],\"kingdom_synergies\":[[\"CanReplaceAction\"]],\"kingdom_anti_synergies\":[[\"WillReplaceAction\"]],\"card_types\":[\"Action\",\"Attack\",\"Curse\"],\"art_url\":[\"www.image1.com\",\"www.image2.com\"],\"relationships\":{\"setup_card_ids\":[],\"pile_card_ids\":[],\"heirloom_card_id\":null},\"texts\":[]}");
     }
 
     #[test]
@@ -655,6 +757,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -662,9 +765,11 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
-        let json = "{\"id\":1,\"name\":\"test name\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"set_id\":0,\"editions\":[1,2],\"card_tags\":[\"Costs4\"],\"kingdom_requirements\":[[\"AddCardToTopOfDeck\"]],\"kingdom_synergies\":[[\"CanReplaceAction\"]],\"kingdom_anti_synergies\":[[\"WillReplaceAction\"]],\"card_types\":[\"Action\",\"Attack\",\"Curse\"],\"art_url\":[\"www.image1.com\",\"www.image2.com\"]}";
+        let json = "{\"id\":1,\"name\":\"test name\",\"supply_card\":true,\"basic_card\":false,\"card_counts\":[10,10,10,10,10],\"cost\":{\"coins\":4,\"potion\":false,\"debt\":0,\"modifiers\":[]},\"set_id\":0,\"editions\":[1,2],\"card_tags\":[\"Costs4\"],\"kingdom_requirements\":[[\"AddCardToTopOfDeck\"]],\"kingdom_synergies\":[[\"CanReplaceAction\"]],\"kingdom_anti_synergies\":[[\"WillReplaceAction\"]],\"card_types\":[\"Action\",\"Attack\",\"Curse\"],\"art_url\":[\"www.image1.com\",\"www.image2.com\"],\"relationships\":{\"setup_card_ids\":[],\"pile_card_ids\":[],\"heirloom_card_id\":null},\"texts\":[]}";
         let json_set: Card = serde_json::from_str(json).unwrap();
 
         assert_eq!(json_set, card);
@@ -678,6 +783,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -685,11 +791,13 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert_eq!(
             format!("{card:?}"), 
-            "Card { id: 1, name: \"test name\", supply_card: true, basic_card: false, card_counts: [10, 10, 10, 10, 10], set_id: 0, editions: [1, 2], card_tags: [Costs4], kingdom_requirements: [[AddCardToTopOfDeck]], kingdom_synergies: [[CanReplaceAction]], kingdom_anti_synergies: [[WillReplaceAction]], card_types: [Action, Attack, Curse], art_url: [\"www.image1.com\", \"www.image2.com\"] }");
+            "Card { id: 1, name: \"test name\", supply_card: true, basic_card: false, card_counts: [10, 10, 10, 10, 10], cost: Cost { coins: 4, potion: false, debt: 0, modifiers: [] }, set_id: 0, editions: [1, 2], card_tags: [Costs4], kingdom_requirements: [[AddCardToTopOfDeck]], kingdom_synergies: [[CanReplaceAction]], kingdom_anti_synergies: [[WillReplaceAction]], card_types: [Action, Attack, Curse], art_url: [\"www.image1.com\", \"www.image2.com\"], relationships: CardRelationships { setup_card_ids: [], pile_card_ids: [], heirloom_card_id: None }, texts: [] }");
     }
 
     #[test]
@@ -700,6 +808,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -707,7 +816,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert!(
             card.get_art_path(1).ends_with("\\cards\\test-name_1.jpeg") ||
@@ -727,6 +838,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -734,7 +846,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let _ = card.get_art_path(45);
     }
@@ -748,6 +862,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -755,7 +870,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         assert_eq!(card.get_art_file_name(1), "test-name_1");
         assert_eq!(card.get_art_file_name(2), "test-name_2");
@@ -770,6 +887,7 @@ mod test {
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [1, 2].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -777,7 +895,9 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec()
+            art_url: ["www.image1.com".to_string(), "www.image2.com".to_string()].to_vec(),
+            relationships: CardRelationships::default(),
+            texts: vec![]
         };
         let _ = card.get_art_file_name(45);
     }
@@ -0,0 +1,145 @@
+use std::collections::BTreeSet;
+
+use rocket::FromFormField;
+
+use super::{card::Card, card_tag::CardTag, card_type::CardType, owned_editions::OwnedEditions, state::card_data::CardData};
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, FromFormField)]
+pub enum TagMatch {
+    #[default]
+    All,
+    Any,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CardQuery {
+    pub card_type: Option<CardType>,
+    pub card_tags: Vec<CardTag>,
+    pub tag_match: TagMatch,
+    pub set_id: Option<u8>,
+    pub edition: Option<u8>,
+    pub supply_card: Option<bool>,
+    pub basic_card: Option<bool>,
+    pub owned_editions: OwnedEditions,
+}
+
+fn intersect(candidates: Option<BTreeSet<u16>>, ids: BTreeSet<u16>) -> Option<BTreeSet<u16>> {
+    match candidates {
+        Some(candidates) => Some(candidates.intersection(&ids).copied().collect()),
+        None => Some(ids),
+    }
+}
+
+fn index_ids(ids: Option<&Vec<u16>>) -> BTreeSet<u16> {
+    ids.map(|ids| ids.iter().copied().collect()).unwrap_or_default()
+}
+
+/// Every card matching the query in id order.
+/// Set, type and tag filters are answered from the card data indexes, the rest are checked card by card.
+pub fn query_cards<'a>(card_data: &'a CardData, query: &CardQuery) -> Vec<&'a Card> {
+    let mut candidates: Option<BTreeSet<u16>> = None;
+
+    if let Some(set_id) = query.set_id {
+        candidates = intersect(candidates, index_ids(card_data.set_index.get(&set_id)));
+    }
+    if let Some(card_type) = query.card_type {
+        candidates = intersect(candidates, index_ids(card_data.card_type_index.get(&card_type)));
+    }
+    if !query.card_tags.is_empty() {
+        let tag_ids = query.card_tags.iter().map(|card_tag| index_ids(card_data.card_tag_index.get(card_tag)));
+        let tag_ids = match query.tag_match {
+            TagMatch::All => tag_ids.reduce(|a, b| a.intersection(&b).copied().collect()),
+            TagMatch::Any => tag_ids.reduce(|a, b| a.union(&b).copied().collect()),
+        };
+        candidates = intersect(candidates, tag_ids.unwrap_or_default());
+    }
+
+    let candidates = candidates.unwrap_or_else(|| card_data.id_card_map.keys().copied().collect());
+    candidates.iter()
+        .filter_map(|id| card_data.id_card_map.get(id))
+        .filter(|card| query.edition.is_none_or(|edition| card.editions.contains(&edition)))
+        .filter(|card| query.owned_editions.owns(card.set_id, &card.editions))
+        .filter(|card| query.supply_card.is_none_or(|supply_card| card.supply_card == supply_card))
+        .filter(|card| query.basic_card.is_none_or(|basic_card| card.basic_card == basic_card))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::state::card_data::build_card_data;
+
+    fn names(cards: &[&Card]) -> Vec<String> {
+        cards.iter().map(|card| card.name.clone()).collect()
+    }
+
+    #[test]
+    fn test_query_cards_everything() {
+        let card_data = build_card_data();
+        let cards = query_cards(&card_data, &CardQuery::default());
+        assert_eq!(cards.len(), card_data.id_card_map.len());
+        assert_eq!(cards[0].id, 0);
+    }
+
+    #[test]
+    fn test_query_cards_card_type() {
+        let card_data = build_card_data();
+        let query = CardQuery { set_id: Some(1), card_type: Some(CardType::Attack), ..Default::default() };
+        assert_eq!(
+            names(&query_cards(&card_data, &query)),
+            vec!["Bureaucrat", "Militia", "Spy", "Thief", "Bandit", "Witch"]
+        );
+    }
+
+    #[test]
+    fn test_query_cards_tags_all() {
+        let card_data = build_card_data();
+        let query = CardQuery {
+            set_id: Some(1),
+            card_tags: vec![CardTag::IsAttack, CardTag::Costs5],
+            ..Default::default()
+        };
+        assert_eq!(names(&query_cards(&card_data, &query)), vec!["Bandit", "Witch"]);
+    }
+
+    #[test]
+    fn test_query_cards_tags_any() {
+        let card_data = build_card_data();
+        let query = CardQuery {
+            set_id: Some(1),
+            card_tags: vec![CardTag::CurseAttack, CardTag::Costs6],
+            tag_match: TagMatch::Any,
+            ..Default::default()
+        };
+        assert_eq!(names(&query_cards(&card_data, &query)), vec!["Witch", "Artisan", "Adventurer"]);
+    }
+
+    #[test]
+    fn test_query_cards_set_and_edition() {
+        let card_data = build_card_data();
+        let query = CardQuery { set_id: Some(1), edition: Some(1), card_tags: vec![CardTag::Costs6], ..Default::default() };
+        assert_eq!(names(&query_cards(&card_data, &query)), vec!["Adventurer"]);
+
+        let query = CardQuery { set_id: Some(9), ..Default::default() };
+        assert!(query_cards(&card_data, &query).is_empty());
+    }
+
+    #[test]
+    fn test_query_cards_owned_editions() {
+        let card_data = build_card_data();
+        let mut owned_editions = OwnedEditions::default();
+        owned_editions.add(1, 2);
+        let query = CardQuery { card_tags: vec![CardTag::Costs6], owned_editions, ..Default::default() };
+        assert_eq!(names(&query_cards(&card_data, &query)), vec!["Artisan"]);
+    }
+
+    #[test]
+    fn test_query_cards_supply_and_basic() {
+        let card_data = build_card_data();
+        let query = CardQuery { basic_card: Some(true), ..Default::default() };
+        assert_eq!(query_cards(&card_data, &query).len(), 7);
+
+        let query = CardQuery { set_id: Some(1), basic_card: Some(false), supply_card: Some(true), ..Default::default() };
+        assert_eq!(query_cards(&card_data, &query).len(), 32);
+    }
+}
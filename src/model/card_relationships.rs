@@ -0,0 +1,55 @@
+use rocket::serde::{Deserialize, Serialize};
+
+/// How a card depends on other cards when a game is set up.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CardRelationships {
+    // piles that must be set out when this card is in the kingdom, such as Spoils for Marauder or Madman for Hermit
+    #[serde(default)]
+    pub setup_card_ids: Vec<u16>,
+    // the cards that make up this card's pile when it is a split pile, such as Encampment and Plunder
+    #[serde(default)]
+    pub pile_card_ids: Vec<u16>,
+    // the Heirloom that replaces one Copper in every starting deck
+    #[serde(default)]
+    pub heirloom_card_id: Option<u16>,
+}
+
+impl CardRelationships {
+    /// Every card id this card refers to.
+    pub fn related_card_ids(&self) -> impl Iterator<Item = u16> + '_ {
+        self.setup_card_ids.iter()
+            .chain(self.pile_card_ids.iter())
+            .chain(self.heirloom_card_id.iter())
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    #[test]
+    fn test_deserialize_defaults() {
+        let relationships: CardRelationships = serde_json::from_str("{}").unwrap();
+        assert_eq!(relationships, CardRelationships::default());
+
+        let relationships: CardRelationships = serde_json::from_str("{\"heirloom_card_id\":1200}").unwrap();
+        assert_eq!(relationships.heirloom_card_id, Some(1200));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let relationships = CardRelationships { setup_card_ids: vec![800], pile_card_ids: vec![], heirloom_card_id: None };
+        assert_eq!(
+            serde_json::to_string(&relationships).unwrap(),
+            "{\"setup_card_ids\":[800],\"pile_card_ids\":[],\"heirloom_card_id\":null}");
+    }
+
+    #[test]
+    fn test_related_card_ids() {
+        let relationships = CardRelationships { setup_card_ids: vec![800, 801], pile_card_ids: vec![802], heirloom_card_id: Some(803) };
+        assert_eq!(relationships.related_card_ids().collect::<Vec<u16>>(), vec![800, 801, 802, 803]);
+    }
+}
@@ -0,0 +1,195 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use super::{card::Card, state::card_data::CardData};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub enum MatchKind {
+    Exact,
+    Prefix,
+    Contains,
+    Fuzzy,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CardSearchResult {
+    pub match_kind: MatchKind,
+    pub distance: usize,
+    pub card: Card,
+}
+
+/// Lower case with everything but letters and digits removed, so "Throne_Room" and "throne room" are the same.
+pub fn normalise_card_name(name: &str) -> String {
+    name.chars()
+        .filter(|character| character.is_alphanumeric())
+        .flat_map(|character| character.to_lowercase())
+        .collect()
+}
+
+/// Levenshtein distance between two strings.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Shorter queries are within an edit or two of nearly every name so they only match exactly, by prefix or by substring.
+const MIN_FUZZY_QUERY_LENGTH: usize = 4;
+
+/// The most edits a query can be from a name and still match, longer queries allow more typos.
+fn max_distance(query: &str) -> usize {
+    match query.chars().count() {
+        length if length < MIN_FUZZY_QUERY_LENGTH => 0,
+        length => (length / 3).max(1),
+    }
+}
+
+fn match_card(query: &str, card: &Card) -> Option<(MatchKind, usize)> {
+    let name = normalise_card_name(&card.name);
+    if name == query {
+        return Some((MatchKind::Exact, 0));
+    }
+    if name.starts_with(query) {
+        return Some((MatchKind::Prefix, 0));
+    }
+    if name.contains(query) {
+        return Some((MatchKind::Contains, 0));
+    }
+
+    // compare to the start of the name too so a misspelt partial name still matches
+    let name_start: String = name.chars().take(query.chars().count()).collect();
+    let distance = edit_distance(query, &name).min(edit_distance(query, &name_start));
+    if distance <= max_distance(query) {
+        return Some((MatchKind::Fuzzy, distance));
+    }
+    None
+}
+
+/// Cards matching `query` best first, exact matches before prefixes, substrings and then fuzzy matches.
+pub fn search_cards(card_data: &CardData, query: &str, limit: usize) -> Vec<CardSearchResult> {
+    let query = normalise_card_name(query);
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let mut results: Vec<CardSearchResult> = card_data.id_card_map.values()
+        .filter_map(|card| match_card(&query, card).map(|(match_kind, distance)| CardSearchResult {
+            match_kind,
+            distance,
+            card: card.clone()
+        }))
+        .collect();
+    results.sort_by(|a, b| {
+        a.match_kind.cmp(&b.match_kind)
+            .then(a.distance.cmp(&b.distance))
+            .then(a.card.name.cmp(&b.card.name))
+    });
+    results.truncate(limit);
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::state::card_data::build_card_data;
+
+    fn result_names(results: &[CardSearchResult]) -> Vec<&str> {
+        results.iter().map(|result| result.card.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_normalise_card_name() {
+        assert_eq!(normalise_card_name("Throne Room"), "throneroom");
+        assert_eq!(normalise_card_name("Throne_Room"), "throneroom");
+        assert_eq!(normalise_card_name(" throne-room! "), "throneroom");
+        assert_eq!(normalise_card_name("Council Room"), "councilroom");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("chapel", "chapel"), 0);
+        assert_eq!(edit_distance("chpael", "chapel"), 2);
+    }
+
+    #[test]
+    fn test_search_cards_exact() {
+        let card_data = build_card_data();
+        for query in ["throne room", "Throne_Room", "THRONE ROOM"] {
+            let results = search_cards(&card_data, query, 10);
+            assert_eq!(results[0].card.name, "Throne Room");
+            assert_eq!(results[0].match_kind, MatchKind::Exact);
+        }
+    }
+
+    #[test]
+    fn test_search_cards_prefix() {
+        let card_data = build_card_data();
+        let results = search_cards(&card_data, "thron", 10);
+        assert_eq!(results[0].card.name, "Throne Room");
+        assert_eq!(results[0].match_kind, MatchKind::Prefix);
+    }
+
+    #[test]
+    fn test_search_cards_prefix_ranked_by_name() {
+        let card_data = build_card_data();
+        let results = search_cards(&card_data, "m", 3);
+        assert_eq!(result_names(&results), vec!["Market", "Masquerade", "Merchant"]);
+    }
+
+    #[test]
+    fn test_search_cards_contains() {
+        let card_data = build_card_data();
+        let results = search_cards(&card_data, "room", 10);
+        assert_eq!(result_names(&results), vec!["Council Room", "Throne Room"]);
+        assert_eq!(results[0].match_kind, MatchKind::Contains);
+    }
+
+    #[test]
+    fn test_search_cards_fuzzy() {
+        let card_data = build_card_data();
+        let results = search_cards(&card_data, "labratory", 10);
+        assert_eq!(results[0].card.name, "Laboratory");
+        assert_eq!(results[0].match_kind, MatchKind::Fuzzy);
+        assert_eq!(results[0].distance, 1);
+
+        let results = search_cards(&card_data, "thorne", 10);
+        assert_eq!(results[0].card.name, "Throne Room");
+    }
+
+    #[test]
+    fn test_search_cards_short_query_not_fuzzy() {
+        let card_data = build_card_data();
+        assert_eq!(max_distance("x"), 0);
+        assert_eq!(max_distance("lab"), 0);
+        assert_eq!(max_distance("labr"), 1);
+        for query in ["x", "qz", "thx"] {
+            assert!(search_cards(&card_data, query, 10).iter().all(|result| result.match_kind != MatchKind::Fuzzy));
+        }
+    }
+
+    #[test]
+    fn test_search_cards_no_match() {
+        let card_data = build_card_data();
+        assert!(search_cards(&card_data, "zzzzzzzz", 10).is_empty());
+        assert!(search_cards(&card_data, " _ ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_cards_limit() {
+        let card_data = build_card_data();
+        assert_eq!(search_cards(&card_data, "c", 2).len(), 2);
+    }
+}
@@ -1,6 +1,9 @@
+use rocket::FromFormField;
 use rocket::serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+use super::card_type::CardType;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize, Serialize, FromFormField)]
 #[serde(crate = "rocket::serde")]
 pub enum CardTag{
     //Action
@@ -68,7 +71,7 @@ pub enum CardTag{
     DiscardingAttack,
     DiscardToTopOfDeckAttack,
 
-    //Card costs
+    //Card costs, kept in step with the coins of `Card.cost` for tag based synergies
     Costs0,
     Costs1,
     Costs2,
@@ -86,6 +89,45 @@ pub enum CardTag{
     IsCurse,
     IsAttack,
     IsReaction,
+    IsDuration,
+    IsNight,
+    IsFate,
+    IsDoom,
+    IsHeirloom,
+    IsSpirit,
+    IsZombie,
+    IsReserve,
+    IsTraveller,
+    IsLooter,
+    IsRuins,
+    IsShelter,
+    IsKnight,
+    IsCastle,
+    IsGathering,
+    IsPrize,
+    IsLiaison,
+    IsAlly,
+    IsAugur,
+    IsClash,
+    IsFort,
+    IsOdyssey,
+    IsTownsfolk,
+    IsWizard,
+    IsCommand,
+}
+
+impl CardTag {
+    pub fn is_cost_tag(&self) -> bool {
+        matches!(
+            self,
+            CardTag::Costs0 | CardTag::Costs1 | CardTag::Costs2 | CardTag::Costs3 | CardTag::Costs4 |
+            CardTag::Costs5 | CardTag::Costs6 | CardTag::Costs7 | CardTag::Costs8
+        )
+    }
+
+    pub fn is_type_tag(&self) -> bool {
+        CardType::ALL.iter().any(|card_type| card_type.type_tag() == *self)
+    }
 }
 
 
@@ -153,4 +195,18 @@ mod test {
             format!("{card_tag:?}"), 
             "CanDiscardCard");
     }
+
+    #[test]
+    fn test_is_cost_tag() {
+        assert!(CardTag::Costs0.is_cost_tag());
+        assert!(CardTag::Costs8.is_cost_tag());
+        assert!(!CardTag::IsAction.is_cost_tag());
+    }
+
+    #[test]
+    fn test_is_type_tag() {
+        assert!(CardTag::IsReaction.is_type_tag());
+        assert!(CardTag::IsWizard.is_type_tag());
+        assert!(!CardTag::Costs3.is_type_tag());
+    }
 }
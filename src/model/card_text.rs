@@ -0,0 +1,80 @@
+use rocket::serde::{Deserialize, Serialize};
+
+/// What a card says in one edition, the wording of 1st and 2nd edition cards often differs.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CardText {
+    pub edition: u8,
+    pub text: String,
+    // official corrections to the printed text
+    #[serde(default)]
+    pub errata: Vec<String>,
+    // rulings for questions that come up at the table
+    #[serde(default)]
+    pub faq: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub enum TextField {
+    Name,
+    Text,
+    Errata,
+    Faq,
+}
+
+impl TextField {
+    /// How much a match in the field counts towards a search score, matching a name beats matching a ruling.
+    pub fn weight(&self) -> usize {
+        match self {
+            TextField::Name => 4,
+            TextField::Text => 3,
+            TextField::Errata => 2,
+            TextField::Faq => 1,
+        }
+    }
+}
+
+impl CardText {
+    pub fn fields(&self) -> impl Iterator<Item = (TextField, &str)> + '_ {
+        std::iter::once((TextField::Text, self.text.as_str()))
+            .chain(self.errata.iter().map(|errata| (TextField::Errata, errata.as_str())))
+            .chain(self.faq.iter().map(|faq| (TextField::Faq, faq.as_str())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    #[test]
+    fn test_deserialize_defaults() {
+        let card_text: CardText = serde_json::from_str("{\"edition\":2,\"text\":\"+3 Cards.\"}").unwrap();
+        assert_eq!(card_text, CardText { edition: 2, text: "+3 Cards.".to_string(), errata: vec![], faq: vec![] });
+    }
+
+    #[test]
+    fn test_serialize() {
+        let card_text = CardText { edition: 1, text: "+3 Cards.".to_string(), errata: vec![], faq: vec!["A ruling.".to_string()] };
+        assert_eq!(
+            serde_json::to_string(&card_text).unwrap(),
+            "{\"edition\":1,\"text\":\"+3 Cards.\",\"errata\":[],\"faq\":[\"A ruling.\"]}");
+    }
+
+    #[test]
+    fn test_fields() {
+        let card_text = CardText {
+            edition: 1,
+            text: "text".to_string(),
+            errata: vec!["errata".to_string()],
+            faq: vec!["faq one".to_string(), "faq two".to_string()]
+        };
+        assert_eq!(card_text.fields().collect::<Vec<(TextField, &str)>>(), vec![
+            (TextField::Text, "text"),
+            (TextField::Errata, "errata"),
+            (TextField::Faq, "faq one"),
+            (TextField::Faq, "faq two"),
+        ]);
+    }
+}
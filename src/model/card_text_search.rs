@@ -0,0 +1,173 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use super::{card::Card, card_text::{CardText, TextField}, state::card_data::CardData};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CardTextSearchResult {
+    pub score: usize,
+    pub edition: u8,
+    pub matched_fields: Vec<TextField>,
+    pub card: Card,
+}
+
+/// The lower case words of a query or a piece of card text, "+$2." gives "2".
+pub fn text_search_terms(text: &str) -> Vec<String> {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+/// How many words of `text` start with `term`.
+fn term_hits(term: &str, words: &[String]) -> usize {
+    words.iter().filter(|word| word.starts_with(term)).count()
+}
+
+/// Scores one edition of a card, every term has to appear in the name or that edition's text.
+fn match_card_text(terms: &[String], card: &Card, card_text: &CardText) -> Option<(usize, Vec<TextField>)> {
+    let fields: Vec<(TextField, Vec<String>)> = std::iter::once((TextField::Name, card.name.as_str()))
+        .chain(card_text.fields())
+        .map(|(field, text)| (field, text_search_terms(text)))
+        .collect();
+
+    let mut score = 0;
+    let mut matched_fields = vec![];
+    for term in terms {
+        let mut term_score = 0;
+        for (field, words) in fields.iter() {
+            let hits = term_hits(term, words);
+            if hits > 0 {
+                term_score += hits * field.weight();
+                if !matched_fields.contains(field) {
+                    matched_fields.push(*field);
+                }
+            }
+        }
+        if term_score == 0 {
+            return None;
+        }
+        score += term_score;
+    }
+    matched_fields.sort();
+    Some((score, matched_fields))
+}
+
+/// Cards whose name, text, errata or FAQ contain every word of `query`, best first.
+/// Each card is listed once under its best scoring edition, or only under `edition` when one is given.
+pub fn search_card_text(card_data: &CardData, query: &str, edition: Option<u8>, limit: usize) -> Vec<CardTextSearchResult> {
+    let terms = text_search_terms(query);
+    if terms.is_empty() {
+        return vec![];
+    }
+
+    let mut results: Vec<CardTextSearchResult> = card_data.id_card_map.values()
+        .filter_map(|card| {
+            card.texts.iter()
+                .filter(|card_text| edition.is_none_or(|edition| card_text.edition == edition))
+                .filter_map(|card_text| match_card_text(&terms, card, card_text)
+                    .map(|(score, matched_fields)| (score, card_text.edition, matched_fields)))
+                // ties go to the newest wording
+                .max_by_key(|(score, edition, _)| (*score, *edition))
+                .map(|(score, edition, matched_fields)| CardTextSearchResult { score, edition, matched_fields, card: card.clone() })
+        })
+        .collect();
+    results.sort_by(|a, b| b.score.cmp(&a.score).then(a.card.name.cmp(&b.card.name)));
+    results.truncate(limit);
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{
+        data_loaders::card_data_loader::{get_base_card_vec, get_dominion_card_vec},
+        state::card_data::{build_card_data, build_card_data_from_cards}
+    };
+
+    fn result_names(results: &[CardTextSearchResult]) -> Vec<&str> {
+        results.iter().map(|result| result.card.name.as_str()).collect()
+    }
+
+    fn card_text(edition: u8, text: &str) -> CardText {
+        CardText { edition, text: text.to_string(), errata: vec![], faq: vec![] }
+    }
+
+    #[test]
+    fn test_text_search_terms() {
+        assert_eq!(text_search_terms("+1 Card. +$2."), vec!["1", "card", "2"]);
+        assert_eq!(text_search_terms("  Throne-Room  "), vec!["throne", "room"]);
+        assert!(text_search_terms("+$ .").is_empty());
+    }
+
+    #[test]
+    fn test_search_card_text_every_term() {
+        let card_data = build_card_data();
+        let results = search_card_text(&card_data, "curse each other player", None, 10);
+        let mut names = result_names(&results);
+        names.sort_unstable();
+        assert_eq!(names, vec!["Replace", "Torturer", "Witch"]);
+        assert!(results.iter().all(|result| result.matched_fields == vec![TextField::Text]));
+    }
+
+    #[test]
+    fn test_search_card_text_prefix() {
+        let card_data = build_card_data();
+        let results = search_card_text(&card_data, "trash copp", None, 10);
+        assert_eq!(result_names(&results), vec!["Bandit", "Moneylender"]);
+    }
+
+    #[test]
+    fn test_search_card_text_edition_wording() {
+        let card_data = build_card_data();
+        let results = search_card_text(&card_data, "immediately", None, 10);
+        assert_eq!(result_names(&results), vec!["Chancellor", "Mining Village"]);
+        assert!(results.iter().all(|result| result.edition == 1));
+
+        assert!(search_card_text(&card_data, "his hand", Some(2), 10).is_empty());
+        assert!(!search_card_text(&card_data, "his hand", Some(1), 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_card_text_scores_name_first() {
+        let mut cards = get_base_card_vec();
+        cards.extend(get_dominion_card_vec());
+        let mut village = cards.iter().find(|card| card.name == "Village").unwrap().clone();
+        village.id = 190;
+        village.name = "Gain Village".to_string();
+        village.texts = vec![card_text(2, "+2 Actions.")];
+        let mut workshop = cards.iter().find(|card| card.name == "Workshop").unwrap().clone();
+        workshop.id = 191;
+        workshop.name = "Other Workshop".to_string();
+        workshop.texts = vec![CardText { edition: 2, text: "+1 Card.".to_string(), errata: vec!["Gain a Silver.".to_string()], faq: vec![] }];
+        cards.extend([village, workshop]);
+        let card_data = build_card_data_from_cards(cards, vec![]);
+
+        let results = search_card_text(&card_data, "gain", None, 3);
+        assert_eq!(results[0].card.name, "Gain Village");
+        assert_eq!(results[0].matched_fields, vec![TextField::Name]);
+        assert_eq!(results.len(), 3);
+        assert!(results.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_search_card_text_searches_faq() {
+        let card_data = build_card_data();
+        let results = search_card_text(&card_data, "shuffle", None, 10);
+        assert_eq!(result_names(&results), vec!["Library"]);
+        assert_eq!(results[0].matched_fields, vec![TextField::Faq]);
+    }
+
+    #[test]
+    fn test_search_card_text_empty_query() {
+        let card_data = build_card_data();
+        assert!(search_card_text(&card_data, "+$", None, 10).is_empty());
+        assert!(search_card_text(&card_data, "zzzzzzzz", None, 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_card_text_limit() {
+        let card_data = build_card_data();
+        assert_eq!(search_card_text(&card_data, "card", None, 2).len(), 2);
+    }
+}
@@ -1,6 +1,9 @@
+use rocket::FromFormField;
 use rocket::serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+use super::card_tag::CardTag;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize, Serialize, FromFormField)]
 #[serde(crate = "rocket::serde")]
 pub enum CardType{
     Action,
@@ -8,12 +11,103 @@ pub enum CardType{
     Victory,
     Curse,
     Attack,
-    Reaction
+    Reaction,
+
+    //Seaside
+    Duration,
+
+    //Nocturne
+    Night,
+    Fate,
+    Doom,
+    Heirloom,
+    Spirit,
+    Zombie,
+
+    //Adventures
+    Reserve,
+    Traveller,
+
+    //Dark Ages
+    Looter,
+    Ruins,
+    Shelter,
+    Knight,
+
+    //Empires
+    Castle,
+    Gathering,
+
+    //Cornucopia
+    Prize,
+
+    //Allies
+    Liaison,
+    Ally,
+    Augur,
+    Clash,
+    Fort,
+    Odyssey,
+    Townsfolk,
+    Wizard,
+
+    //Cards like Overlord that play a card from the supply
+    Command,
 }
 
+impl CardType {
+    pub const ALL: [CardType; 31] = [
+        CardType::Action, CardType::Treasure, CardType::Victory, CardType::Curse, CardType::Attack,
+        CardType::Reaction, CardType::Duration, CardType::Night, CardType::Fate, CardType::Doom,
+        CardType::Heirloom, CardType::Spirit, CardType::Zombie, CardType::Reserve, CardType::Traveller,
+        CardType::Looter, CardType::Ruins, CardType::Shelter, CardType::Knight, CardType::Castle,
+        CardType::Gathering, CardType::Prize, CardType::Liaison, CardType::Ally, CardType::Augur,
+        CardType::Clash, CardType::Fort, CardType::Odyssey, CardType::Townsfolk, CardType::Wizard,
+        CardType::Command
+    ];
+
+    /// The `Is` tag every card of this type carries.
+    pub fn type_tag(&self) -> CardTag {
+        match self {
+            CardType::Action => CardTag::IsAction,
+            CardType::Treasure => CardTag::IsTreasure,
+            CardType::Victory => CardTag::IsVictory,
+            CardType::Curse => CardTag::IsCurse,
+            CardType::Attack => CardTag::IsAttack,
+            CardType::Reaction => CardTag::IsReaction,
+            CardType::Duration => CardTag::IsDuration,
+            CardType::Night => CardTag::IsNight,
+            CardType::Fate => CardTag::IsFate,
+            CardType::Doom => CardTag::IsDoom,
+            CardType::Heirloom => CardTag::IsHeirloom,
+            CardType::Spirit => CardTag::IsSpirit,
+            CardType::Zombie => CardTag::IsZombie,
+            CardType::Reserve => CardTag::IsReserve,
+            CardType::Traveller => CardTag::IsTraveller,
+            CardType::Looter => CardTag::IsLooter,
+            CardType::Ruins => CardTag::IsRuins,
+            CardType::Shelter => CardTag::IsShelter,
+            CardType::Knight => CardTag::IsKnight,
+            CardType::Castle => CardTag::IsCastle,
+            CardType::Gathering => CardTag::IsGathering,
+            CardType::Prize => CardTag::IsPrize,
+            CardType::Liaison => CardTag::IsLiaison,
+            CardType::Ally => CardTag::IsAlly,
+            CardType::Augur => CardTag::IsAugur,
+            CardType::Clash => CardTag::IsClash,
+            CardType::Fort => CardTag::IsFort,
+            CardType::Odyssey => CardTag::IsOdyssey,
+            CardType::Townsfolk => CardTag::IsTownsfolk,
+            CardType::Wizard => CardTag::IsWizard,
+            CardType::Command => CardTag::IsCommand,
+        }
+    }
+}
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
+
     use super::*;
     use rocket::serde::json::serde_json;
 
@@ -76,4 +170,24 @@ mod test {
             format!("{card_type:?}"), 
             "Action");
     }
+
+    #[test]
+    fn test_type_tag() {
+        assert_eq!(CardType::Action.type_tag(), CardTag::IsAction);
+        assert_eq!(CardType::Reaction.type_tag(), CardTag::IsReaction);
+    }
+
+    #[test]
+    fn test_every_type_has_its_own_tag() {
+        let tags: HashSet<CardTag> = CardType::ALL.iter().map(|card_type| card_type.type_tag()).collect();
+        assert_eq!(tags.len(), CardType::ALL.len());
+        assert_eq!(CardType::Traveller.type_tag(), CardTag::IsTraveller);
+        assert_eq!(CardType::Command.type_tag(), CardTag::IsCommand);
+    }
+
+    #[test]
+    fn test_deserialize_expansion_types() {
+        let card_types: Vec<CardType> = serde_json::from_str("[\"Duration\",\"Night\",\"Liaison\"]").unwrap();
+        assert_eq!(card_types, vec![CardType::Duration, CardType::Night, CardType::Liaison]);
+    }
 }
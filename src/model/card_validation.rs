@@ -0,0 +1,412 @@
+use std::{collections::{HashMap, HashSet}, error::Error, fmt};
+
+use super::{
+    data_loaders::{card_data_loader::set_card_id_range, landscape_data_loader::landscape_id_range},
+    card::Card,
+    card_tag::CardTag,
+    card_type::CardType,
+    cost::Cost,
+    landscape::Landscape,
+    set::Set
+};
+
+/// The basic cards are shared by every set so they are not listed in sets.json.
+pub const BASIC_CARD_SET_ID: u8 = 0;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ValidationError {
+    SetUrlCount { set_name: String },
+    ArtUrlCount { card_name: String, editions: usize, art_urls: usize },
+    UnknownSetId { card_name: String, set_id: u8 },
+    CardIdOutOfRange { card_name: String, card_id: u16, set_id: u8 },
+    UnknownEdition { card_name: String, set_name: String, edition: u8 },
+    TextEdition { card_name: String, edition: u8 },
+    MissingTypeTag { card_name: String, card_type: CardType },
+    MissingCardType { card_name: String, card_tag: CardTag },
+    CostTagCount { card_name: String, cost_tags: usize },
+    CostTagMismatch { card_name: String, cost: Cost },
+    DuplicateName { card_name: String, other_card_name: String },
+    UnknownRelatedCardId { card_name: String, card_id: u16 },
+    LandscapeArtUrlCount { landscape_name: String, editions: usize, art_urls: usize },
+    LandscapeUnknownSetId { landscape_name: String, set_id: u8 },
+    LandscapeIdOutOfRange { landscape_name: String, landscape_id: u16, set_id: u8 },
+    LandscapeUnknownEdition { landscape_name: String, set_name: String, edition: u8 },
+    DuplicateLandscapeId { landscape_name: String, other_landscape_name: String, landscape_id: u16 },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::SetUrlCount { set_name } =>
+                write!(f, "Set {} needs a cover art, rule book and icon url for every edition.", set_name),
+            ValidationError::ArtUrlCount { card_name, editions, art_urls } =>
+                write!(f, "Card {} has {} editions but {} art urls.", card_name, editions, art_urls),
+            ValidationError::UnknownSetId { card_name, set_id } =>
+                write!(f, "Card {} has unknown set id {}.", card_name, set_id),
+            ValidationError::CardIdOutOfRange { card_name, card_id, set_id } =>
+                write!(f, "Card {} has id {} which is outside the card ids of set {}.", card_name, card_id, set_id),
+            ValidationError::UnknownEdition { card_name, set_name, edition } =>
+                write!(f, "Card {} has edition {} but set {} does not.", card_name, edition, set_name),
+            ValidationError::TextEdition { card_name, edition } =>
+                write!(f, "Card {} has text for edition {} which it is not in or has that text twice.", card_name, edition),
+            ValidationError::MissingTypeTag { card_name, card_type } =>
+                write!(f, "Card {} is of type {:?} but is missing the {:?} tag.", card_name, card_type, card_type.type_tag()),
+            ValidationError::MissingCardType { card_name, card_tag } =>
+                write!(f, "Card {} has the {:?} tag but not the matching card type.", card_name, card_tag),
+            ValidationError::CostTagCount { card_name, cost_tags } =>
+                write!(f, "Card {} must have exactly one cost tag, found {}.", card_name, cost_tags),
+            ValidationError::CostTagMismatch { card_name, cost } =>
+                write!(f, "Card {} costs {} but its cost tag does not match.", card_name, cost),
+            ValidationError::DuplicateName { card_name, other_card_name } =>
+                write!(f, "Card {} has the same name as card {}.", card_name, other_card_name),
+            ValidationError::UnknownRelatedCardId { card_name, card_id } =>
+                write!(f, "Card {} refers to unknown card id {}.", card_name, card_id),
+            ValidationError::LandscapeArtUrlCount { landscape_name, editions, art_urls } =>
+                write!(f, "Landscape {} has {} editions but {} art urls.", landscape_name, editions, art_urls),
+            ValidationError::LandscapeUnknownSetId { landscape_name, set_id } =>
+                write!(f, "Landscape {} has unknown set id {}.", landscape_name, set_id),
+            ValidationError::LandscapeIdOutOfRange { landscape_name, landscape_id, set_id } =>
+                write!(f, "Landscape {} has id {} which is outside the landscape ids of set {}.", landscape_name, landscape_id, set_id),
+            ValidationError::LandscapeUnknownEdition { landscape_name, set_name, edition } =>
+                write!(f, "Landscape {} has edition {} but set {} does not.", landscape_name, edition, set_name),
+            ValidationError::DuplicateLandscapeId { landscape_name, other_landscape_name, landscape_id } =>
+                write!(f, "Landscape {} has the same id {} as landscape {}.", landscape_name, landscape_id, other_landscape_name),
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Checks the cards and sets agree with each other, every problem found is returned.
+pub fn validate_card_data(cards: &[Card], sets: &[Set]) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    for set in sets {
+        if set.editions.is_empty() ||
+            set.editions.len() != set.cover_art_url.len() ||
+            set.editions.len() != set.rule_book_url.len() ||
+            set.editions.len() != set.icon_url.len()
+        {
+            errors.push(ValidationError::SetUrlCount { set_name: set.name.clone() });
+        }
+    }
+
+    let id_set_map: HashMap<u8, &Set> = sets.iter().map(|set| (set.id, set)).collect();
+    let card_ids: HashSet<u16> = cards.iter().map(|card| card.id).collect();
+    let mut lower_case_names: HashMap<String, &Card> = HashMap::new();
+    for card in cards {
+        errors.extend(validate_card(card, &id_set_map));
+        for card_id in card.relationships.related_card_ids().filter(|card_id| !card_ids.contains(card_id)) {
+            errors.push(ValidationError::UnknownRelatedCardId { card_name: card.name.clone(), card_id });
+        }
+        if let Some(other) = lower_case_names.insert(card.name.to_lowercase(), card) {
+            errors.push(ValidationError::DuplicateName { card_name: card.name.clone(), other_card_name: other.name.clone() });
+        }
+    }
+    errors
+}
+
+/// The sets no card belongs to yet, these are reported by the validator but are not a problem with the data.
+pub fn sets_without_card_data<'a>(cards: &[Card], sets: &'a [Set]) -> Vec<&'a Set> {
+    let set_ids: HashSet<u8> = cards.iter().map(|card| card.set_id).collect();
+    sets.iter().filter(|set| !set_ids.contains(&set.id)).collect()
+}
+
+/// Checks every landscape belongs to a known set and edition and has an id of its own.
+pub fn validate_landscape_data(landscapes: &[Landscape], sets: &[Set]) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    let id_set_map: HashMap<u8, &Set> = sets.iter().map(|set| (set.id, set)).collect();
+    let mut id_landscape_map: HashMap<u16, &Landscape> = HashMap::new();
+    for landscape in landscapes {
+        let landscape_name = || landscape.name.clone();
+        if landscape.editions.len() != landscape.art_url.len() {
+            errors.push(ValidationError::LandscapeArtUrlCount {
+                landscape_name: landscape_name(),
+                editions: landscape.editions.len(),
+                art_urls: landscape.art_url.len()
+            });
+        }
+
+        if !landscape_id_range(landscape.set_id).contains(&landscape.id) {
+            errors.push(ValidationError::LandscapeIdOutOfRange {
+                landscape_name: landscape_name(),
+                landscape_id: landscape.id,
+                set_id: landscape.set_id
+            });
+        }
+
+        match id_set_map.get(&landscape.set_id) {
+            Some(set) => {
+                for edition in landscape.editions.iter().filter(|edition| !set.editions.contains(edition)) {
+                    errors.push(ValidationError::LandscapeUnknownEdition {
+                        landscape_name: landscape_name(),
+                        set_name: set.name.clone(),
+                        edition: *edition
+                    });
+                }
+            },
+            None => errors.push(ValidationError::LandscapeUnknownSetId { landscape_name: landscape_name(), set_id: landscape.set_id }),
+        }
+
+        if let Some(other) = id_landscape_map.insert(landscape.id, landscape) {
+            errors.push(ValidationError::DuplicateLandscapeId {
+                landscape_name: landscape_name(),
+                other_landscape_name: other.name.clone(),
+                landscape_id: landscape.id
+            });
+        }
+    }
+    errors
+}
+
+fn validate_card(card: &Card, id_set_map: &HashMap<u8, &Set>) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    let card_name = || card.name.clone();
+
+    if card.editions.len() != card.art_url.len() {
+        errors.push(ValidationError::ArtUrlCount {
+            card_name: card_name(),
+            editions: card.editions.len(),
+            art_urls: card.art_url.len()
+        });
+    }
+
+    if !set_card_id_range(card.set_id).contains(&card.id) {
+        errors.push(ValidationError::CardIdOutOfRange { card_name: card_name(), card_id: card.id, set_id: card.set_id });
+    }
+
+    match id_set_map.get(&card.set_id) {
+        Some(set) => {
+            for edition in card.editions.iter().filter(|edition| !set.editions.contains(edition)) {
+                errors.push(ValidationError::UnknownEdition { card_name: card_name(), set_name: set.name.clone(), edition: *edition });
+            }
+        },
+        None if card.set_id == BASIC_CARD_SET_ID => {},
+        None => errors.push(ValidationError::UnknownSetId { card_name: card_name(), set_id: card.set_id }),
+    }
+
+    let mut text_editions = vec![];
+    for card_text in card.texts.iter() {
+        if !card.editions.contains(&card_text.edition) || text_editions.contains(&card_text.edition) {
+            errors.push(ValidationError::TextEdition { card_name: card_name(), edition: card_text.edition });
+        }
+        text_editions.push(card_text.edition);
+    }
+
+    for card_type in card.card_types.iter().filter(|card_type| !card.card_tags.contains(&card_type.type_tag())) {
+        errors.push(ValidationError::MissingTypeTag { card_name: card_name(), card_type: *card_type });
+    }
+    for card_tag in card.card_tags.iter().filter(|card_tag| card_tag.is_type_tag()) {
+        if !card.card_types.iter().any(|card_type| card_type.type_tag() == *card_tag) {
+            errors.push(ValidationError::MissingCardType { card_name: card_name(), card_tag: *card_tag });
+        }
+    }
+
+    let cost_tags: Vec<&CardTag> = card.card_tags.iter().filter(|card_tag| card_tag.is_cost_tag()).collect();
+    if cost_tags.len() != 1 {
+        errors.push(ValidationError::CostTagCount { card_name: card_name(), cost_tags: cost_tags.len() });
+    } else if card.cost.cost_tag().as_ref() != Some(cost_tags[0]) {
+        errors.push(ValidationError::CostTagMismatch { card_name: card_name(), cost: card.cost.clone() });
+    }
+    errors
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::data_loaders::{card_data_loader::get_all_card_vec, landscape_data_loader::get_landscape_vec, set_data_loader::get_set_vec};
+
+    fn chapel() -> Card {
+        get_all_card_vec().into_iter().find(|card| card.name == "Chapel").unwrap()
+    }
+
+    fn alms() -> Landscape {
+        get_landscape_vec().into_iter().find(|landscape| landscape.name == "Alms").unwrap()
+    }
+
+    #[test]
+    fn test_raw_data_is_valid() {
+        assert_eq!(validate_card_data(&get_all_card_vec(), &get_set_vec()), vec![]);
+        assert_eq!(validate_landscape_data(&get_landscape_vec(), &get_set_vec()), vec![]);
+    }
+
+    #[test]
+    fn test_sets_without_card_data() {
+        let sets = get_set_vec();
+        let set_names: Vec<&str> = sets_without_card_data(&get_all_card_vec(), &sets).iter().map(|set| set.name.as_str()).collect();
+        assert!(!set_names.contains(&"Dominion"));
+        assert!(!set_names.contains(&"Intrigue"));
+        assert!(set_names.contains(&"Guilds"));
+        assert_eq!(sets_without_card_data(&[chapel()], &sets[..1]), Vec::<&Set>::new());
+    }
+
+    #[test]
+    fn test_landscape_id_out_of_range() {
+        let mut landscape = alms();
+        landscape.id = 1000;
+        assert_eq!(validate_landscape_data(&[landscape], &get_set_vec()), vec![
+            ValidationError::LandscapeIdOutOfRange { landscape_name: "Alms".to_string(), landscape_id: 1000, set_id: 10 }
+        ]);
+    }
+
+    #[test]
+    fn test_landscape_unknown_set_and_edition() {
+        let mut landscape = alms();
+        landscape.editions.push(2);
+        landscape.art_url.push("http://example.com/Alms.jpg".to_string());
+        let mut other = alms();
+        other.name = "Other Alms".to_string();
+        other.id = 31001;
+        other.set_id = 200;
+        other.art_url.clear();
+        assert_eq!(validate_landscape_data(&[landscape, other], &get_set_vec()), vec![
+            ValidationError::LandscapeUnknownEdition { landscape_name: "Alms".to_string(), set_name: "Adventures".to_string(), edition: 2 },
+            ValidationError::LandscapeArtUrlCount { landscape_name: "Other Alms".to_string(), editions: 1, art_urls: 0 },
+            ValidationError::LandscapeIdOutOfRange { landscape_name: "Other Alms".to_string(), landscape_id: 31001, set_id: 200 },
+            ValidationError::LandscapeUnknownSetId { landscape_name: "Other Alms".to_string(), set_id: 200 },
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_landscape_id() {
+        let landscape = alms();
+        let mut other = alms();
+        other.name = "Borrow".to_string();
+        assert_eq!(validate_landscape_data(&[landscape, other], &get_set_vec()), vec![
+            ValidationError::DuplicateLandscapeId {
+                landscape_name: "Borrow".to_string(),
+                other_landscape_name: "Alms".to_string(),
+                landscape_id: 31000
+            }
+        ]);
+    }
+
+    #[test]
+    fn test_art_url_count() {
+        let mut card = chapel();
+        card.art_url.pop();
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::ArtUrlCount { card_name: "Chapel".to_string(), editions: 2, art_urls: 1 }
+        ]);
+    }
+
+    #[test]
+    fn test_unknown_set_id() {
+        let mut card = chapel();
+        card.set_id = 200;
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::CardIdOutOfRange { card_name: "Chapel".to_string(), card_id: 101, set_id: 200 },
+            ValidationError::UnknownSetId { card_name: "Chapel".to_string(), set_id: 200 }
+        ]);
+    }
+
+    #[test]
+    fn test_card_id_out_of_range() {
+        let mut card = chapel();
+        card.id = 201;
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::CardIdOutOfRange { card_name: "Chapel".to_string(), card_id: 201, set_id: 1 }
+        ]);
+    }
+
+    #[test]
+    fn test_basic_cards_have_no_set() {
+        let copper = get_all_card_vec().into_iter().find(|card| card.name == "Copper").unwrap();
+        assert_eq!(copper.set_id, BASIC_CARD_SET_ID);
+        assert_eq!(validate_card_data(&[copper], &get_set_vec()), vec![]);
+    }
+
+    #[test]
+    fn test_unknown_edition() {
+        let mut card = chapel();
+        card.editions.push(3);
+        card.art_url.push("http://example.com/Chapel.jpg".to_string());
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::UnknownEdition { card_name: "Chapel".to_string(), set_name: "Dominion".to_string(), edition: 3 }
+        ]);
+    }
+
+    #[test]
+    fn test_text_edition() {
+        let mut card = chapel();
+        let mut card_text = card.texts[0].clone();
+        card.texts.push(card_text.clone());
+        card_text.edition = 3;
+        card.texts.push(card_text);
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::TextEdition { card_name: "Chapel".to_string(), edition: 1 },
+            ValidationError::TextEdition { card_name: "Chapel".to_string(), edition: 3 },
+        ]);
+    }
+
+    #[test]
+    fn test_card_types_agree_with_tags() {
+        let mut card = chapel();
+        card.card_types.push(CardType::Attack);
+        card.card_tags.push(CardTag::IsTreasure);
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::MissingTypeTag { card_name: "Chapel".to_string(), card_type: CardType::Attack },
+            ValidationError::MissingCardType { card_name: "Chapel".to_string(), card_tag: CardTag::IsTreasure },
+        ]);
+    }
+
+    #[test]
+    fn test_cost_tag_count() {
+        let mut card = chapel();
+        card.card_tags.push(CardTag::Costs3);
+        let mut other = chapel();
+        other.name = "Other Chapel".to_string();
+        other.card_tags.retain(|card_tag| !card_tag.is_cost_tag());
+        assert_eq!(validate_card_data(&[card, other], &get_set_vec()), vec![
+            ValidationError::CostTagCount { card_name: "Chapel".to_string(), cost_tags: 2 },
+            ValidationError::CostTagCount { card_name: "Other Chapel".to_string(), cost_tags: 0 },
+        ]);
+    }
+
+    #[test]
+    fn test_cost_tag_mismatch() {
+        let mut card = chapel();
+        card.cost = Cost::from_coins(3);
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::CostTagMismatch { card_name: "Chapel".to_string(), cost: Cost::from_coins(3) }
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_name_ignores_case() {
+        let card = chapel();
+        let mut other = chapel();
+        other.name = "CHAPEL".to_string();
+        assert_eq!(validate_card_data(&[card, other], &get_set_vec()), vec![
+            ValidationError::DuplicateName { card_name: "CHAPEL".to_string(), other_card_name: "Chapel".to_string() }
+        ]);
+    }
+
+    #[test]
+    fn test_unknown_related_card_id() {
+        let mut card = chapel();
+        card.relationships.setup_card_ids.push(101);
+        card.relationships.heirloom_card_id = Some(65500);
+        assert_eq!(validate_card_data(&[card], &get_set_vec()), vec![
+            ValidationError::UnknownRelatedCardId { card_name: "Chapel".to_string(), card_id: 65500 }
+        ]);
+    }
+
+    #[test]
+    fn test_set_url_count() {
+        let mut sets = get_set_vec();
+        sets[0].icon_url.pop();
+        assert_eq!(validate_card_data(&[], &sets), vec![
+            ValidationError::SetUrlCount { set_name: "Dominion".to_string() }
+        ]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ValidationError::MissingTypeTag { card_name: "Chapel".to_string(), card_type: CardType::Attack }.to_string(),
+            "Card Chapel is of type Attack but is missing the IsAttack tag.");
+        assert_eq!(
+            ValidationError::CostTagCount { card_name: "Chapel".to_string(), cost_tags: 0 }.to_string(),
+            "Card Chapel must have exactly one cost tag, found 0.");
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+use rocket::serde::{Deserialize, Serialize};
+
+use super::card_tag::CardTag;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub enum CostModifier {
+    // Guilds cards that can be bought for more than their cost, written as $4+
+    Overpay,
+    // costs that change during the game or have a special rule, written as $8*
+    Variable,
+}
+
+/// What a card costs to buy or gain, a card can cost coins, a potion and debt at once.
+/// Costs order by coins then potion then debt, the order cards are laid out in a kingdom.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Cost {
+    pub coins: u8,
+    #[serde(default)]
+    pub potion: bool,
+    #[serde(default)]
+    pub debt: u8,
+    #[serde(default)]
+    pub modifiers: Vec<CostModifier>,
+}
+
+impl Cost {
+    pub fn from_coins(coins: u8) -> Self {
+        Self { coins, ..Default::default() }
+    }
+
+    /// The `CostsN` tag used by tag based synergies, only coins are counted.
+    pub fn cost_tag(&self) -> Option<CardTag> {
+        match self.coins {
+            0 => Some(CardTag::Costs0),
+            1 => Some(CardTag::Costs1),
+            2 => Some(CardTag::Costs2),
+            3 => Some(CardTag::Costs3),
+            4 => Some(CardTag::Costs4),
+            5 => Some(CardTag::Costs5),
+            6 => Some(CardTag::Costs6),
+            7 => Some(CardTag::Costs7),
+            8 => Some(CardTag::Costs8),
+            _ => None,
+        }
+    }
+}
+
+/// Written the way the cards print it, `$4`, `$2P`, `8D`, `$4+`.
+impl fmt::Display for Cost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut written = false;
+        if self.coins > 0 || (!self.potion && self.debt == 0) {
+            write!(f, "${}", self.coins)?;
+            written = true;
+        }
+        if self.potion {
+            if !written { write!(f, "$")?; }
+            write!(f, "P")?;
+        }
+        if self.debt > 0 {
+            write!(f, "{}D", self.debt)?;
+        }
+        for modifier in self.modifiers.iter() {
+            match modifier {
+                CostModifier::Overpay => write!(f, "+")?,
+                CostModifier::Variable => write!(f, "*")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    #[test]
+    fn test_deserialize_coins_only() {
+        let cost: Cost = serde_json::from_str("{\"coins\":4}").unwrap();
+        assert_eq!(cost, Cost::from_coins(4));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let cost = Cost { coins: 2, potion: true, debt: 0, modifiers: vec![] };
+        assert_eq!(serde_json::to_string(&cost).unwrap(), "{\"coins\":2,\"potion\":true,\"debt\":0,\"modifiers\":[]}");
+        let cost = Cost { coins: 4, potion: false, debt: 0, modifiers: vec![CostModifier::Overpay] };
+        assert_eq!(serde_json::to_string(&cost).unwrap(), "{\"coins\":4,\"potion\":false,\"debt\":0,\"modifiers\":[\"Overpay\"]}");
+    }
+
+    #[test]
+    fn test_order() {
+        let mut costs = vec![
+            Cost { debt: 8, ..Default::default() },
+            Cost::from_coins(5),
+            Cost { coins: 2, potion: true, ..Default::default() },
+            Cost::from_coins(2),
+            Cost { coins: 2, modifiers: vec![CostModifier::Overpay], ..Default::default() },
+        ];
+        costs.sort();
+        assert_eq!(costs, vec![
+            Cost { debt: 8, ..Default::default() },
+            Cost::from_coins(2),
+            Cost { coins: 2, modifiers: vec![CostModifier::Overpay], ..Default::default() },
+            Cost { coins: 2, potion: true, ..Default::default() },
+            Cost::from_coins(5),
+        ]);
+    }
+
+    #[test]
+    fn test_cost_tag() {
+        assert_eq!(Cost::from_coins(0).cost_tag(), Some(CardTag::Costs0));
+        assert_eq!(Cost { coins: 3, potion: true, ..Default::default() }.cost_tag(), Some(CardTag::Costs3));
+        assert_eq!(Cost::from_coins(11).cost_tag(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Cost::from_coins(0).to_string(), "$0");
+        assert_eq!(Cost::from_coins(5).to_string(), "$5");
+        assert_eq!(Cost { coins: 0, potion: true, ..Default::default() }.to_string(), "$P");
+        assert_eq!(Cost { coins: 2, potion: true, ..Default::default() }.to_string(), "$2P");
+        assert_eq!(Cost { debt: 8, ..Default::default() }.to_string(), "8D");
+        assert_eq!(Cost { coins: 4, modifiers: vec![CostModifier::Overpay], ..Default::default() }.to_string(), "$4+");
+        assert_eq!(Cost { coins: 8, modifiers: vec![CostModifier::Variable], ..Default::default() }.to_string(), "$8*");
+    }
+}
@@ -1,46 +1,131 @@
-use std::{include_bytes, collections::HashMap};
-use rocket::serde::json::serde_json;
+use std::{include_bytes, collections::{HashMap, HashSet}, ops::Range};
 
-use super::super::card::Card;
+use super::super::{card::Card, set::Set};
+use super::data_dir::{DataDir, DataLoadError, MalformedRecord};
+use super::set_data_loader::load_set_vec;
 
 
 pub static BASE_CARD_JSON_BYTES: &[u8] = include_bytes!("../raw_data/basic_cards.json");
 pub static DOMINION_CARD_JSON_BYTES: &[u8] = include_bytes!("../raw_data/dominion_cards.json");
+pub static INTRIGUE_CARD_JSON_BYTES: &[u8] = include_bytes!("../raw_data/intrigue_cards.json");
 
+pub const BASE_CARD_FILE_NAME: &str = "basic_cards.json";
+pub const DOMINION_CARD_FILE_NAME: &str = "dominion_cards.json";
+
+/// Every set owns the card ids from `set id * CARD_IDS_PER_SET`, the basic cards use set id 0 so start at 0.
+pub const CARD_IDS_PER_SET: u16 = 100;
+
+// the sets with card data built into the binary, the other sets only have cards when their file is in the data directory
+static EMBEDDED_SET_CARD_FILES: [(u8, &[u8]); 2] = [
+    (1, DOMINION_CARD_JSON_BYTES),
+    (2, INTRIGUE_CARD_JSON_BYTES),
+];
+static NO_EMBEDDED_CARDS: &[u8] = b"[]";
+
+const EMBEDDED_CARD_DATA_ERROR: &str = "The embedded card data is malformed.";
+
+
+pub fn set_card_id_range(set_id: u8) -> Range<u16> {
+    let start = set_id as u16 * CARD_IDS_PER_SET;
+    start..start + CARD_IDS_PER_SET
+}
+
+/// The card file of a set, `Dark Ages` cards are in `dark_ages_cards.json`.
+pub fn set_card_file_name(set: &Set) -> String {
+    format!("{}_cards.json", set.name.to_lowercase().replace(' ', "_"))
+}
+
+fn embedded_set_card_bytes(set_id: u8) -> &'static [u8] {
+    EMBEDDED_SET_CARD_FILES.iter()
+        .find(|(embedded_set_id, _)| *embedded_set_id == set_id)
+        .map_or(NO_EMBEDDED_CARDS, |(_, embedded_bytes)| *embedded_bytes)
+}
 
 #[allow(dead_code)]
 pub fn get_base_card_vec() -> Vec<Card> {
-    let set_json = String::from_utf8_lossy(BASE_CARD_JSON_BYTES).into_owned();
-    serde_json::from_str(&set_json).unwrap()
+    DataDir::embedded().load_records(BASE_CARD_FILE_NAME, BASE_CARD_JSON_BYTES).expect(EMBEDDED_CARD_DATA_ERROR)
 }
 
 #[allow(dead_code)]
 pub fn get_dominion_card_vec() -> Vec<Card> {
-    let set_json = String::from_utf8_lossy(DOMINION_CARD_JSON_BYTES).into_owned();
-    serde_json::from_str(&set_json).unwrap()
+    DataDir::embedded().load_records(DOMINION_CARD_FILE_NAME, DOMINION_CARD_JSON_BYTES).expect(EMBEDDED_CARD_DATA_ERROR)
+}
+
+#[allow(dead_code)]
+pub fn get_set_card_vec(set: &Set) -> Vec<Card> {
+    DataDir::embedded().load_records(&set_card_file_name(set), embedded_set_card_bytes(set.id)).expect(EMBEDDED_CARD_DATA_ERROR)
 }
 
 #[allow(dead_code)]
 pub fn get_all_card_vec() -> Vec<Card> {
+    load_all_card_vec(&DataDir::embedded()).expect(EMBEDDED_CARD_DATA_ERROR)
+}
+
+/// Loads the basic cards then the card file of every set in `sets.json`,
+/// the error lists the malformed records of every file and any repeated card id.
+pub fn load_all_card_vec(data_dir: &DataDir) -> Result<Vec<Card>, DataLoadError> {
+    match load_set_vec(data_dir) {
+        Ok(sets) => load_card_files(data_dir, &sets, vec![]),
+        Err(error) => load_card_files(data_dir, &[], error.malformed_records),
+    }
+}
+
+/// Loads the basic cards then the card file of each of `sets`, for callers that have already read `sets.json`.
+pub fn load_set_card_vec(data_dir: &DataDir, sets: &[Set]) -> Result<Vec<Card>, DataLoadError> {
+    load_card_files(data_dir, sets, vec![])
+}
+
+fn load_card_files(data_dir: &DataDir, sets: &[Set], mut malformed_records: Vec<MalformedRecord>) -> Result<Vec<Card>, DataLoadError> {
     let mut all_cards = vec![];
-    all_cards.extend(get_base_card_vec());
-    all_cards.extend(get_dominion_card_vec());
-    all_cards
+    let card_files = [(BASE_CARD_FILE_NAME.to_string(), BASE_CARD_JSON_BYTES)].into_iter()
+        .chain(sets.iter().map(|set| (set_card_file_name(set), embedded_set_card_bytes(set.id))));
+
+    for (file_name, embedded_bytes) in card_files {
+        match data_dir.load_records::<Card>(&file_name, embedded_bytes) {
+            Ok(cards) => {
+                let mut seen_ids: HashSet<u16> = all_cards.iter().map(|card: &Card| card.id).collect();
+                for (index, card) in cards.iter().enumerate() {
+                    if !seen_ids.insert(card.id) {
+                        malformed_records.push(MalformedRecord {
+                            file_name: file_name.clone(),
+                            index: Some(index),
+                            message: format!("card id {} is used more than once", card.id)
+                        });
+                    }
+                }
+                all_cards.extend(cards);
+            },
+            Err(records) => malformed_records.extend(records),
+        }
+    }
+
+    match malformed_records.is_empty() {
+        true => Ok(all_cards),
+        false => Err(DataLoadError { malformed_records }),
+    }
 }
 
 #[allow(dead_code)]
 pub fn get_all_card_id_map() -> HashMap<u16, Card> {
+    card_id_map(get_all_card_vec())
+}
+
+#[allow(dead_code)]
+pub fn get_all_card_name_map() -> HashMap<String, Card> {
+    card_name_map(get_all_card_vec())
+}
+
+pub fn card_id_map(cards: Vec<Card>) -> HashMap<u16, Card> {
     let mut map = HashMap::new();
-    for card in get_all_card_vec() {
+    for card in cards {
         map.insert(card.id, card);
     }
     map
 }
 
-#[allow(dead_code)]
-pub fn get_all_card_name_map() -> HashMap<String, Card> {
+pub fn card_name_map(cards: Vec<Card>) -> HashMap<String, Card> {
     let mut map = HashMap::new();
-    for card in get_all_card_vec() {
+    for card in cards {
         map.insert(card.name.clone(), card.clone());
         map.insert(card.name.clone().to_ascii_lowercase(), card);
     }
@@ -49,15 +134,65 @@ pub fn get_all_card_name_map() -> HashMap<String, Card> {
 
 #[cfg(test)]
 mod test {
+    use std::{env::temp_dir, fs::{create_dir_all, remove_dir_all, write}};
+
+    use rocket::serde::json::{serde_json, Value};
+
     use super::*;
+    use crate::model::data_loaders::set_data_loader::{SET_FILE_NAME, get_set_vec};
 
     #[test]
     fn test_get_all_card_vec() {
         let all_cards = get_all_card_vec();
-        let base_cards = get_base_card_vec();
-        let dominion_cards = get_dominion_card_vec();
+        let set_cards: usize = get_set_vec().iter().map(|set| get_set_card_vec(set).len()).sum();
+
+        assert_eq!(all_cards.len(), get_base_card_vec().len() + set_cards);
+    }
+
+    #[test]
+    fn test_set_card_file_name() {
+        let sets = get_set_vec();
+        assert_eq!(set_card_file_name(&sets[0]), DOMINION_CARD_FILE_NAME);
+        assert_eq!(set_card_file_name(sets.iter().find(|set| set.name == "Dark Ages").unwrap()), "dark_ages_cards.json");
+    }
 
-        assert_eq!(all_cards.len(), base_cards.len() + dominion_cards.len());
+    #[test]
+    fn test_set_card_id_range() {
+        assert_eq!(set_card_id_range(0), 0..100);
+        assert_eq!(set_card_id_range(2), 200..300);
+        assert_eq!(set_card_id_range(15), 1500..1600);
+    }
+
+    #[test]
+    fn test_set_cards_use_their_set_id_range() {
+        for set in get_set_vec() {
+            for card in get_set_card_vec(&set) {
+                assert_eq!(card.set_id, set.id);
+                assert!(set_card_id_range(set.id).contains(&card.id));
+            }
+        }
+    }
+
+    #[test]
+    fn test_embedded_cards_have_text_for_every_edition() {
+        for card in get_all_card_vec().iter().filter(|card| !card.basic_card) {
+            let text_editions: Vec<u8> = card.texts.iter().map(|card_text| card_text.edition).collect();
+            assert_eq!(text_editions, card.editions, "{}", card.name);
+        }
+    }
+
+    #[test]
+    fn test_get_set_card_vec_intrigue() {
+        let intrigue = get_set_vec().into_iter().find(|set| set.name == "Intrigue").unwrap();
+        let cards = get_set_card_vec(&intrigue);
+        assert_eq!(cards[0].id, 200);
+        assert_eq!(cards[0].name, "Courtyard");
+    }
+
+    #[test]
+    fn test_get_set_card_vec_without_embedded_file() {
+        let allies = get_set_vec().into_iter().find(|set| set.name == "Allies").unwrap();
+        assert_eq!(get_set_card_vec(&allies), vec![]);
     }
 
     #[test]
@@ -111,4 +246,88 @@ mod test {
             assert_eq!(card, &card_map[&card.name.to_ascii_lowercase()]);
         }
     }
+
+    fn test_dir(name: &str) -> std::path::PathBuf {
+        let folder = temp_dir().join(format!("dominion_card_loader_{}_{}", name, std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    #[test]
+    fn test_load_all_card_vec_embedded() {
+        assert_eq!(load_all_card_vec(&DataDir::embedded()).unwrap(), get_all_card_vec());
+    }
+
+    #[test]
+    fn test_load_all_card_vec_from_directory() {
+        let folder = test_dir("from_directory");
+        let mut cards = get_base_card_vec();
+        cards[0].name = "Fixed Copper".to_string();
+        write(folder.join(BASE_CARD_FILE_NAME), serde_json::to_string(&cards).unwrap()).unwrap();
+
+        let all_cards = load_all_card_vec(&DataDir::new(&folder)).unwrap();
+        assert_eq!(all_cards[0].name, "Fixed Copper");
+        assert_eq!(all_cards.len(), get_all_card_vec().len());
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_load_all_card_vec_lists_malformed_records_from_every_file() {
+        let folder = test_dir("malformed");
+        let mut base_values: Vec<Value> = serde_json::from_slice(BASE_CARD_JSON_BYTES).unwrap();
+        base_values[2]["card_tags"] = Value::from(vec!["NotATag"]);
+        write(folder.join(BASE_CARD_FILE_NAME), serde_json::to_string(&base_values).unwrap()).unwrap();
+        write(folder.join(DOMINION_CARD_FILE_NAME), "not json").unwrap();
+
+        let error = load_all_card_vec(&DataDir::new(&folder)).unwrap_err();
+        assert_eq!(error.malformed_records.len(), 2);
+        assert_eq!(error.malformed_records[0].file_name, BASE_CARD_FILE_NAME);
+        assert_eq!(error.malformed_records[0].index, Some(2));
+        assert_eq!(error.malformed_records[1].file_name, DOMINION_CARD_FILE_NAME);
+        assert_eq!(error.malformed_records[1].index, None);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_load_all_card_vec_repeated_id() {
+        let folder = test_dir("repeated_id");
+        let mut cards = get_dominion_card_vec();
+        cards[3].id = 1;
+        write(folder.join(DOMINION_CARD_FILE_NAME), serde_json::to_string(&cards).unwrap()).unwrap();
+
+        let error = load_all_card_vec(&DataDir::new(&folder)).unwrap_err();
+        assert_eq!(error.malformed_records, vec![MalformedRecord {
+            file_name: DOMINION_CARD_FILE_NAME.to_string(),
+            index: Some(3),
+            message: "card id 1 is used more than once".to_string()
+        }]);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_load_all_card_vec_reads_set_file_from_directory() {
+        let folder = test_dir("set_file");
+        let mut card = get_dominion_card_vec()[0].clone();
+        card.id = 300;
+        card.name = "Embargo".to_string();
+        card.set_id = 3;
+        write(folder.join("seaside_cards.json"), serde_json::to_string(&vec![card.clone()]).unwrap()).unwrap();
+
+        let all_cards = load_all_card_vec(&DataDir::new(&folder)).unwrap();
+        assert_eq!(all_cards.len(), get_all_card_vec().len() + 1);
+        assert!(all_cards.contains(&card));
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_load_all_card_vec_malformed_sets() {
+        let folder = test_dir("malformed_sets");
+        write(folder.join(SET_FILE_NAME), "not json").unwrap();
+
+        let error = load_all_card_vec(&DataDir::new(&folder)).unwrap_err();
+        assert_eq!(error.malformed_records.len(), 1);
+        assert_eq!(error.malformed_records[0].file_name, SET_FILE_NAME);
+        let _ = remove_dir_all(folder);
+    }
 }
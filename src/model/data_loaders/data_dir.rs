@@ -0,0 +1,169 @@
+use std::{error::Error, fmt, fs, io::ErrorKind, path::PathBuf};
+
+use rocket::serde::{de::DeserializeOwned, json::{serde_json, Value}};
+
+/// A record that could not be loaded, `index` is the position of the record in its file
+/// and is missing when the whole file could not be read.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MalformedRecord {
+    pub file_name: String,
+    pub index: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for MalformedRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "{} record {}: {}", self.file_name, index, self.message),
+            None => write!(f, "{}: {}", self.file_name, self.message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DataLoadError {
+    pub malformed_records: Vec<MalformedRecord>,
+}
+
+impl fmt::Display for DataLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} malformed records.", self.malformed_records.len())?;
+        for record in self.malformed_records.iter() {
+            write!(f, "\n{}", record)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for DataLoadError {}
+
+/// Where the card and set JSON files are read from.
+/// Files missing from the directory, or every file when there is no directory, come from the embedded data.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct DataDir {
+    pub path: Option<PathBuf>,
+}
+
+impl DataDir {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()) }
+    }
+
+    pub fn embedded() -> Self {
+        Self { path: None }
+    }
+
+    fn read_file(&self, file_name: &str, embedded_bytes: &'static [u8]) -> Result<Vec<u8>, MalformedRecord> {
+        let Some(path) = &self.path else { return Ok(embedded_bytes.to_vec()) };
+        match fs::read(path.join(file_name)) {
+            Ok(bytes) => Ok(bytes),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(embedded_bytes.to_vec()),
+            Err(error) => Err(MalformedRecord { file_name: file_name.to_string(), index: None, message: error.to_string() }),
+        }
+    }
+
+    /// Loads a JSON array file, every record is parsed on its own so one bad record does not hide the rest.
+    pub fn load_records<T: DeserializeOwned>(
+        &self,
+        file_name: &str,
+        embedded_bytes: &'static [u8]
+    ) -> Result<Vec<T>, Vec<MalformedRecord>> {
+        let file_error = |message: String| vec![MalformedRecord { file_name: file_name.to_string(), index: None, message }];
+        let bytes = self.read_file(file_name, embedded_bytes).map_err(|record| vec![record])?;
+        let values: Vec<Value> = serde_json::from_slice(&bytes).map_err(|error| file_error(error.to_string()))?;
+
+        let mut records = vec![];
+        let mut malformed_records = vec![];
+        for (index, value) in values.into_iter().enumerate() {
+            match serde_json::from_value(value) {
+                Ok(record) => records.push(record),
+                Err(error) => malformed_records.push(MalformedRecord {
+                    file_name: file_name.to_string(),
+                    index: Some(index),
+                    message: error.to_string()
+                }),
+            }
+        }
+
+        match malformed_records.is_empty() {
+            true => Ok(records),
+            false => Err(malformed_records),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs::{create_dir_all, remove_dir_all, write}};
+
+    use super::*;
+    use crate::model::set::Set;
+    use crate::model::data_loaders::set_data_loader::{SET_JSON_BYTES, get_set_vec};
+
+    fn test_dir(name: &str) -> PathBuf {
+        let folder = temp_dir().join(format!("dominion_data_dir_{}_{}", name, std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    #[test]
+    fn test_embedded() {
+        let sets: Vec<Set> = DataDir::embedded().load_records("sets.json", SET_JSON_BYTES).unwrap();
+        assert_eq!(sets, get_set_vec());
+    }
+
+    #[test]
+    fn test_missing_file_falls_back_to_embedded() {
+        let folder = test_dir("missing_file");
+        let sets: Vec<Set> = DataDir::new(&folder).load_records("sets.json", SET_JSON_BYTES).unwrap();
+        assert_eq!(sets, get_set_vec());
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_reads_file_from_directory() {
+        let folder = test_dir("reads_file");
+        write(folder.join("sets.json"), serde_json::to_string(&get_set_vec()[..2]).unwrap()).unwrap();
+        let sets: Vec<Set> = DataDir::new(&folder).load_records("sets.json", SET_JSON_BYTES).unwrap();
+        assert_eq!(sets, get_set_vec()[..2]);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_lists_every_malformed_record() {
+        let folder = test_dir("malformed_records");
+        let mut values: Vec<Value> = serde_json::from_slice(SET_JSON_BYTES).unwrap();
+        values[1]["name"] = Value::from(7);
+        values[3].as_object_mut().unwrap().remove("editions");
+        write(folder.join("sets.json"), serde_json::to_string(&values).unwrap()).unwrap();
+
+        let malformed_records = DataDir::new(&folder).load_records::<Set>("sets.json", SET_JSON_BYTES).unwrap_err();
+        assert_eq!(malformed_records.len(), 2);
+        assert_eq!(malformed_records[0].index, Some(1));
+        assert_eq!(malformed_records[0].to_string(), "sets.json record 1: invalid type: integer `7`, expected a string");
+        assert_eq!(malformed_records[1].to_string(), "sets.json record 3: missing field `editions`");
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_not_an_array() {
+        let folder = test_dir("not_an_array");
+        write(folder.join("sets.json"), "{}").unwrap();
+        let malformed_records = DataDir::new(&folder).load_records::<Set>("sets.json", SET_JSON_BYTES).unwrap_err();
+        assert_eq!(malformed_records.len(), 1);
+        assert_eq!(malformed_records[0].index, None);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_data_load_error_display() {
+        let error = DataLoadError { malformed_records: vec![
+            MalformedRecord { file_name: "sets.json".to_string(), index: Some(2), message: "missing field `name`".to_string() },
+            MalformedRecord { file_name: "basic_cards.json".to_string(), index: None, message: "expected value".to_string() },
+        ]};
+        assert_eq!(
+            error.to_string(),
+            "2 malformed records.\nsets.json record 2: missing field `name`\nbasic_cards.json: expected value");
+    }
+}
@@ -0,0 +1,84 @@
+use std::{include_bytes, ops::Range};
+
+use super::super::landscape::Landscape;
+use super::card_data_loader::set_card_id_range;
+use super::data_dir::{DataDir, DataLoadError};
+
+
+pub static LANDSCAPE_JSON_BYTES: &[u8] = include_bytes!("../raw_data/landscapes.json");
+
+pub const LANDSCAPE_FILE_NAME: &str = "landscapes.json";
+
+/// Landscape ids start above every set's card ids, so a landscape id is never also a card id.
+pub const LANDSCAPE_ID_OFFSET: u16 = 30000;
+
+pub fn landscape_id_range(set_id: u8) -> Range<u16> {
+    let card_ids = set_card_id_range(set_id);
+    card_ids.start + LANDSCAPE_ID_OFFSET..card_ids.end + LANDSCAPE_ID_OFFSET
+}
+
+#[allow(dead_code)]
+pub fn get_landscape_vec() -> Vec<Landscape> {
+    load_landscape_vec(&DataDir::embedded()).expect("The embedded landscape data is malformed.")
+}
+
+pub fn load_landscape_vec(data_dir: &DataDir) -> Result<Vec<Landscape>, DataLoadError> {
+    data_dir.load_records(LANDSCAPE_FILE_NAME, LANDSCAPE_JSON_BYTES)
+        .map_err(|malformed_records| DataLoadError { malformed_records })
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::model::{data_loaders::card_data_loader::get_all_card_vec, landscape::LandscapeType};
+
+    #[test]
+    fn test_get_landscape_vec_loads_at_least_one_landscape() {
+        let landscapes = get_landscape_vec();
+        assert_eq!(landscapes[0].id, 31000);
+        assert_eq!(landscapes[0].name, "Alms");
+        assert_eq!(landscapes[0].landscape_type, LandscapeType::Event);
+    }
+
+    #[test]
+    fn test_landscape_id_range() {
+        assert_eq!(landscape_id_range(10), 31000..31100);
+        assert_eq!(landscape_id_range(u8::MAX), 55500..55600);
+        assert!(set_card_id_range(u8::MAX).end <= landscape_id_range(0).start);
+    }
+
+    #[test]
+    fn test_landscape_ids_are_not_card_ids() {
+        let card_ids: HashSet<u16> = get_all_card_vec().iter().map(|card| card.id).collect();
+        for landscape in get_landscape_vec().iter() {
+            assert!(landscape_id_range(landscape.set_id).contains(&landscape.id), "{}", landscape.name);
+            assert!(!card_ids.contains(&landscape.id), "{}", landscape.name);
+        }
+    }
+
+    #[test]
+    fn test_landscape_counts() {
+        let landscapes = get_landscape_vec();
+        let count = |set_id: u8, landscape_type: LandscapeType| landscapes.iter()
+            .filter(|landscape| landscape.set_id == set_id && landscape.landscape_type == landscape_type)
+            .count();
+        assert_eq!(count(10, LandscapeType::Event), 20);
+        assert_eq!(count(11, LandscapeType::Event), 13);
+        assert_eq!(count(11, LandscapeType::Landmark), 21);
+        assert_eq!(count(13, LandscapeType::Project), 20);
+        assert_eq!(count(14, LandscapeType::Event), 20);
+        assert_eq!(count(14, LandscapeType::Way), 20);
+        assert_eq!(count(15, LandscapeType::Ally), 23);
+    }
+
+    #[test]
+    fn test_only_events_and_projects_have_costs() {
+        for landscape in get_landscape_vec().iter() {
+            let can_be_bought = [LandscapeType::Event, LandscapeType::Project].contains(&landscape.landscape_type);
+            assert_eq!(landscape.cost.is_some(), can_be_bought, "{}", landscape.name);
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod set_data_loader;
 pub mod card_data_loader;
+pub mod data_dir;
+pub mod landscape_data_loader;
@@ -1,15 +1,21 @@
 use std::include_bytes;
-use rocket::serde::json::serde_json;
 
 use super::super::set::Set;
+use super::data_dir::{DataDir, DataLoadError};
 
 
 pub static SET_JSON_BYTES: &[u8] = include_bytes!("../raw_data/sets.json");
 
+pub const SET_FILE_NAME: &str = "sets.json";
+
 #[allow(dead_code)]
 pub fn get_set_vec() -> Vec<Set> {
-    let set_json = String::from_utf8_lossy(SET_JSON_BYTES).into_owned();
-    serde_json::from_str(&set_json).unwrap()
+    load_set_vec(&DataDir::embedded()).expect("The embedded set data is malformed.")
+}
+
+pub fn load_set_vec(data_dir: &DataDir) -> Result<Vec<Set>, DataLoadError> {
+    data_dir.load_records(SET_FILE_NAME, SET_JSON_BYTES)
+        .map_err(|malformed_records| DataLoadError { malformed_records })
 }
 
 
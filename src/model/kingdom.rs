@@ -1,22 +1,204 @@
+use std::{collections::HashSet, fmt};
+
 use rocket::serde::{Deserialize, Serialize};
 
+use super::{card::Card, state::card_data::CardData};
+
+pub const MIN_PLAYERS: u8 = 2;
+pub const MAX_PLAYERS: u8 = 6;
+pub const COPPER_ID: u16 = 0;
+pub const STARTING_COPPER_PER_PLAYER: u8 = 7;
+
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(crate = "rocket::serde")]
 pub struct Kingdom {
     pub supply_card_ids: Vec<u16>,
     pub basic_card_ids: Vec<u16>,
+    // Events, Landmarks and the other landscapes, older kingdoms were saved without them
+    #[serde(default)]
+    pub landscape_ids: Vec<u16>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub enum PileLocation {
+    Supply,
+    NonSupply,
+    // Heirlooms are dealt one to each player in place of a Copper
+    StartingDecks,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SupplyPile {
+    pub card_id: u16,
+    pub name: String,
+    pub count: u8,
+    pub location: PileLocation,
+    // the kingdom card that brought this pile into the game, missing for kingdom and basic cards
+    pub added_by: Option<u16>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SupplyError {
+    InvalidPlayerCount(u8),
+    UnknownCardId(u16),
+}
+
+impl fmt::Display for SupplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupplyError::InvalidPlayerCount(players) => write!(
+                f,
+                "Player count must be between {} and {}, got {}.",
+                MIN_PLAYERS,
+                MAX_PLAYERS,
+                players
+            ),
+            SupplyError::UnknownCardId(id) => write!(f, "Unknown card id {}.", id),
+        }
+    }
+}
+
+impl std::error::Error for SupplyError {}
+
+impl Kingdom {
+    /// Every pile for a game with `players` players, the kingdom piles then the basic piles then any Heirlooms.
+    /// Split piles are listed card by card and the piles a kingdom card needs for setup follow that card.
+    /// Copper counts in the card data include the starting decks, so they are dealt out first.
+    pub fn supply_for_players(&self, card_data: &CardData, players: u8) -> Result<Vec<SupplyPile>, SupplyError> {
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
+            return Err(SupplyError::InvalidPlayerCount(players));
+        }
+
+        let mut setup = SetupBuilder { card_data, players, piles: vec![], heirlooms: vec![], seen: HashSet::new() };
+        for id in self.supply_card_ids.iter() {
+            setup.add_card(*id, None)?;
+        }
+
+        let starting_copper = STARTING_COPPER_PER_PLAYER.saturating_sub(setup.heirlooms.len() as u8);
+        for id in self.basic_card_ids.iter() {
+            let card = setup.find_card(*id)?;
+            let mut pile = setup.pile(card, None);
+            if card.id == COPPER_ID {
+                pile.count = pile.count.saturating_sub(starting_copper * players);
+            }
+            setup.piles.push(pile);
+        }
+
+        let mut piles = setup.piles;
+        piles.extend(setup.heirlooms);
+        Ok(piles)
+    }
+}
+
+struct SetupBuilder<'a> {
+    card_data: &'a CardData,
+    players: u8,
+    piles: Vec<SupplyPile>,
+    heirlooms: Vec<SupplyPile>,
+    seen: HashSet<u16>,
+}
+
+impl<'a> SetupBuilder<'a> {
+    fn find_card(&self, id: u16) -> Result<&'a Card, SupplyError> {
+        self.card_data.id_card_map.get(&id).ok_or(SupplyError::UnknownCardId(id))
+    }
+
+    fn pile(&self, card: &Card, added_by: Option<u16>) -> SupplyPile {
+        SupplyPile {
+            card_id: card.id,
+            name: card.name.clone(),
+            count: card.card_counts[(self.players - MIN_PLAYERS) as usize],
+            location: if card.supply_card { PileLocation::Supply } else { PileLocation::NonSupply },
+            added_by
+        }
+    }
+
+    /// Adds the card's pile and every pile it brings in, a pile already in the game is never added twice.
+    fn add_card(&mut self, id: u16, added_by: Option<u16>) -> Result<(), SupplyError> {
+        let card = self.find_card(id)?;
+        if !self.seen.insert(card.id) {
+            return Ok(());
+        }
+
+        let relationships = &card.relationships;
+        if relationships.pile_card_ids.is_empty() {
+            self.piles.push(self.pile(card, added_by));
+        }
+        for pile_card_id in relationships.pile_card_ids.iter() {
+            if *pile_card_id == card.id {
+                self.piles.push(self.pile(card, added_by));
+            } else {
+                self.add_card(*pile_card_id, Some(added_by.unwrap_or(card.id)))?;
+            }
+        }
+        for setup_card_id in relationships.setup_card_ids.iter() {
+            self.add_card(*setup_card_id, Some(card.id))?;
+        }
+        if let Some(heirloom_card_id) = relationships.heirloom_card_id {
+            let heirloom = self.find_card(heirloom_card_id)?;
+            if !self.heirlooms.iter().any(|pile| pile.card_id == heirloom.id) {
+                self.heirlooms.push(SupplyPile {
+                    card_id: heirloom.id,
+                    name: heirloom.name.clone(),
+                    count: self.players,
+                    location: PileLocation::StartingDecks,
+                    added_by: Some(card.id)
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use rocket::serde::json::serde_json;
+    use crate::model::{
+        card_relationships::CardRelationships,
+        data_loaders::card_data_loader::get_all_card_vec,
+        state::card_data::{build_card_data, build_card_data_from_cards}
+    };
+
+    fn related_card(id: u16, name: &str, supply_card: bool, relationships: CardRelationships) -> Card {
+        let mut card = get_all_card_vec().into_iter().find(|card| card.name == "Chapel").unwrap();
+        card.id = id;
+        card.name = name.to_string();
+        card.supply_card = supply_card;
+        card.card_counts = [5, 5, 5, 5, 5];
+        card.relationships = relationships;
+        card
+    }
+
+    fn card_data_with_relationships() -> CardData {
+        let mut cards = get_all_card_vec();
+        cards.extend([
+            related_card(800, "Marauder", true, CardRelationships { setup_card_ids: vec![801, 802], ..Default::default() }),
+            related_card(801, "Spoils", false, CardRelationships::default()),
+            related_card(802, "Ruins", true, CardRelationships { pile_card_ids: vec![803, 804], ..Default::default() }),
+            related_card(803, "Abandoned Mine", true, CardRelationships::default()),
+            related_card(804, "Ruined Library", true, CardRelationships::default()),
+            related_card(805, "Cultist", true, CardRelationships { setup_card_ids: vec![802], ..Default::default() }),
+            related_card(1100, "Encampment", true, CardRelationships { pile_card_ids: vec![1100, 1101], ..Default::default() }),
+            related_card(1101, "Plunder", true, CardRelationships::default()),
+            related_card(1200, "Pixie", true, CardRelationships { heirloom_card_id: Some(1201), ..Default::default() }),
+            related_card(1201, "Goat", false, CardRelationships::default()),
+        ]);
+        build_card_data_from_cards(cards, vec![])
+    }
+
+    fn pile_summary(piles: &[SupplyPile]) -> Vec<(u16, u8, PileLocation, Option<u16>)> {
+        piles.iter().map(|pile| (pile.card_id, pile.count, pile.location, pile.added_by)).collect()
+    }
 
     #[test]
     fn test_clone() {
         let mut kingdom_one = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         let kingdom_two = kingdom_one.clone();
         
@@ -30,11 +212,13 @@ mod test {
     fn test_clone_from() {
         let kingdom_one = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         let mut kingdom_two = Kingdom { 
             supply_card_ids: [13].to_vec(),
-            basic_card_ids: [0, 1, 2, 10].to_vec()
+            basic_card_ids: [0, 1, 2, 10].to_vec(),
+            landscape_ids: [].to_vec()
         };
         kingdom_two.clone_from(&kingdom_one);
         assert_eq!(kingdom_two, kingdom_one);
@@ -44,11 +228,13 @@ mod test {
     fn test_eq_true() {
         let kingdom_one = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         let kingdom_two = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         assert!(kingdom_two == kingdom_one);
     }
@@ -58,11 +244,13 @@ mod test {
     fn test_eq_false_supply_cards() {
         let kingdom_one = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         let kingdom_two = Kingdom { 
             supply_card_ids: [10, 11, 13, 18].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         assert!(kingdom_two != kingdom_one);
     }
@@ -72,11 +260,13 @@ mod test {
     fn test_eq_false_basic_cards() {
         let kingdom_one = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         let kingdom_two = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 2].to_vec()
+            basic_card_ids: [0, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         assert!(kingdom_two != kingdom_one);
     }
@@ -86,13 +276,14 @@ mod test {
     fn test_serialize() {
         let kingdom = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         let json = serde_json::to_string(&kingdom).unwrap();
         
         assert_eq!(
             json,
-            "{\"supply_card_ids\":[10,11,13],\"basic_card_ids\":[0,1,2]}");
+            "{\"supply_card_ids\":[10,11,13],\"basic_card_ids\":[0,1,2],\"landscape_ids\":[]}");
     }
 
     #[test]
@@ -100,24 +291,136 @@ mod test {
     fn test_deserialize() {
         let kingdom = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         let json = "{\"supply_card_ids\":[10,11,13],\"basic_card_ids\":[0,1,2]}";
         let json_set: Kingdom = serde_json::from_str(json).unwrap();
         assert_eq!(json_set, kingdom);
     }
 
+    #[test]
+    fn test_deserialize_landscape_ids() {
+        let json = "{\"supply_card_ids\":[10],\"basic_card_ids\":[0],\"landscape_ids\":[31000]}";
+        let kingdom: Kingdom = serde_json::from_str(json).unwrap();
+        assert_eq!(kingdom.landscape_ids, vec![31000]);
+    }
+
     #[test]
     fn test_fmt() {
         
         let kingdom = Kingdom { 
             supply_card_ids: [10, 11, 13].to_vec(),
-            basic_card_ids: [0, 1, 2].to_vec()
+            basic_card_ids: [0, 1, 2].to_vec(),
+            landscape_ids: [].to_vec()
         };
         assert_eq!(
             format!("{kingdom:?}"), 
-            "Kingdom { supply_card_ids: [10, 11, 13], basic_card_ids: [0, 1, 2] }");
+            "Kingdom { supply_card_ids: [10, 11, 13], basic_card_ids: [0, 1, 2], landscape_ids: [] }");
+    }
+
+    #[test]
+    fn test_supply_for_players() {
+        let card_data = build_card_data();
+        let kingdom = Kingdom {
+            supply_card_ids: [101, 112].to_vec(),
+            basic_card_ids: [0, 1, 2, 6].to_vec(),
+            landscape_ids: [].to_vec()
+        };
+        let supply = kingdom.supply_for_players(&card_data, 2).unwrap();
+        let counts: Vec<(u16, u8)> = supply.iter().map(|pile| (pile.card_id, pile.count)).collect();
+        assert_eq!(counts, vec![(101, 10), (112, 8), (0, 46), (1, 10), (2, 8), (6, 8)]);
+        assert_eq!(supply[0].name, "Chapel");
+    }
+
+    #[test]
+    fn test_supply_for_players_six_players() {
+        let card_data = build_card_data();
+        let kingdom = Kingdom {
+            supply_card_ids: [101, 112].to_vec(),
+            basic_card_ids: [0, 1, 2, 6].to_vec(),
+            landscape_ids: [].to_vec()
+        };
+        let supply = kingdom.supply_for_players(&card_data, 6).unwrap();
+        let counts: Vec<(u16, u8)> = supply.iter().map(|pile| (pile.card_id, pile.count)).collect();
+        assert_eq!(counts, vec![(101, 10), (112, 12), (0, 38), (1, 50), (2, 12), (6, 18)]);
+    }
+
+    #[test]
+    fn test_supply_for_players_invalid_player_count() {
+        let card_data = build_card_data();
+        let kingdom = Kingdom {
+            supply_card_ids: [101].to_vec(),
+            basic_card_ids: [0].to_vec(),
+            landscape_ids: [].to_vec()
+        };
+        assert_eq!(kingdom.supply_for_players(&card_data, 1), Err(SupplyError::InvalidPlayerCount(1)));
+        assert_eq!(kingdom.supply_for_players(&card_data, 7), Err(SupplyError::InvalidPlayerCount(7)));
+    }
+
+    #[test]
+    fn test_supply_for_players_unknown_card_id() {
+        let card_data = build_card_data();
+        let kingdom = Kingdom {
+            supply_card_ids: [65500].to_vec(),
+            basic_card_ids: [0].to_vec(),
+            landscape_ids: [].to_vec()
+        };
+        assert_eq!(kingdom.supply_for_players(&card_data, 2), Err(SupplyError::UnknownCardId(65500)));
+    }
+
+    #[test]
+    fn test_supply_for_players_setup_cards() {
+        let card_data = card_data_with_relationships();
+        let kingdom = Kingdom { supply_card_ids: vec![800, 805, 101], basic_card_ids: vec![1], landscape_ids: vec![] };
+        assert_eq!(pile_summary(&kingdom.supply_for_players(&card_data, 2).unwrap()), vec![
+            (800, 5, PileLocation::Supply, None),
+            (801, 5, PileLocation::NonSupply, Some(800)),
+            (803, 5, PileLocation::Supply, Some(800)),
+            (804, 5, PileLocation::Supply, Some(800)),
+            (805, 5, PileLocation::Supply, None),
+            (101, 10, PileLocation::Supply, None),
+            (1, 10, PileLocation::Supply, None),
+        ]);
+    }
+
+    #[test]
+    fn test_supply_for_players_split_pile() {
+        let card_data = card_data_with_relationships();
+        let kingdom = Kingdom { supply_card_ids: vec![1100], basic_card_ids: vec![], landscape_ids: vec![] };
+        assert_eq!(pile_summary(&kingdom.supply_for_players(&card_data, 3).unwrap()), vec![
+            (1100, 5, PileLocation::Supply, None),
+            (1101, 5, PileLocation::Supply, Some(1100)),
+        ]);
     }
 
+    #[test]
+    fn test_supply_for_players_heirloom_replaces_copper() {
+        let card_data = card_data_with_relationships();
+        let kingdom = Kingdom { supply_card_ids: vec![1200], basic_card_ids: vec![0], landscape_ids: vec![] };
+        assert_eq!(pile_summary(&kingdom.supply_for_players(&card_data, 2).unwrap()), vec![
+            (1200, 5, PileLocation::Supply, None),
+            (0, 48, PileLocation::Supply, None),
+            (1201, 2, PileLocation::StartingDecks, Some(1200)),
+        ]);
+    }
+
+    #[test]
+    fn test_supply_for_players_unknown_related_card_id() {
+        let mut cards = get_all_card_vec();
+        cards.push(related_card(800, "Marauder", true, CardRelationships { setup_card_ids: vec![65500], ..Default::default() }));
+        let card_data = build_card_data_from_cards(cards, vec![]);
+        let kingdom = Kingdom { supply_card_ids: vec![800], basic_card_ids: vec![], landscape_ids: vec![] };
+        assert_eq!(kingdom.supply_for_players(&card_data, 2), Err(SupplyError::UnknownCardId(65500)));
+    }
 
+    #[test]
+    fn test_supply_error_fmt() {
+        assert_eq!(
+            format!("{}", SupplyError::InvalidPlayerCount(7)),
+            "Player count must be between 2 and 6, got 7.");
+        assert_eq!(
+            format!("{}", SupplyError::UnknownCardId(65500)),
+            "Unknown card id 65500.");
+    }
 }
@@ -0,0 +1,716 @@
+use std::{collections::HashSet, fmt};
+
+use rand::{Rng, SeedableRng, seq::SliceRandom};
+use rand_chacha::ChaCha8Rng;
+
+use super::{card::Card, card_tag::CardTag, card_type::CardType, cost::Cost, kingdom::Kingdom, landscape::Landscape, kingdom_score::score_kingdom, owned_editions::OwnedEditions, state::card_data::CardData};
+
+pub const KINGDOM_SIZE: usize = 10;
+pub const MAX_LANDSCAPES: usize = 2;
+pub const MAX_SYNERGY_ATTEMPTS: usize = 100;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum KingdomGeneratorError {
+    NotEnoughCards { required: usize, available: usize },
+    NoCardWithTag(CardTag),
+    InvalidSynergyAttempts(usize),
+    NoCardData(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct KingdomFilter {
+    pub set_ids: Vec<u8>,
+    pub require_tags: Vec<CardTag>,
+    pub exclude_tags: Vec<CardTag>,
+    pub min_coins: Option<u8>,
+    pub max_coins: Option<u8>,
+    pub exclude_potion: bool,
+    pub exclude_debt: bool,
+    pub owned_editions: OwnedEditions,
+    /// How many kingdoms to generate keeping the one with the highest interaction score, `None` generates one.
+    pub synergy_attempts: Option<usize>,
+}
+
+impl KingdomFilter {
+    /// An empty `set_ids` allows cards from every set.
+    pub fn allows(&self, card: &Card) -> bool {
+        self.allows_set(card.set_id) &&
+        self.owned_editions.owns(card.set_id, &card.editions) &&
+        !self.exclude_tags.iter().any(|tag| card.card_tags.contains(tag)) &&
+        self.allows_cost(&card.cost)
+    }
+
+    pub fn allows_landscape(&self, landscape: &Landscape) -> bool {
+        self.allows_set(landscape.set_id) && self.owned_editions.owns(landscape.set_id, &landscape.editions)
+    }
+
+    fn allows_set(&self, set_id: u8) -> bool {
+        self.set_ids.is_empty() || self.set_ids.contains(&set_id)
+    }
+
+    fn allows_cost(&self, cost: &Cost) -> bool {
+        self.min_coins.is_none_or(|min_coins| cost.coins >= min_coins) &&
+        self.max_coins.is_none_or(|max_coins| cost.coins <= max_coins) &&
+        !(self.exclude_potion && cost.potion) &&
+        !(self.exclude_debt && cost.debt > 0)
+    }
+}
+
+impl fmt::Display for KingdomGeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KingdomGeneratorError::NotEnoughCards { required, available } => write!(
+                f,
+                "Not enough cards to build a kingdom, {} required but only {} could be used.",
+                required,
+                available
+            ),
+            KingdomGeneratorError::NoCardWithTag(card_tag) => write!(
+                f,
+                "No card with tag {:?} could be added to the kingdom.",
+                card_tag
+            ),
+            KingdomGeneratorError::InvalidSynergyAttempts(attempts) => write!(
+                f,
+                "Synergy attempts must be between 1 and {}, got {}.",
+                MAX_SYNERGY_ATTEMPTS,
+                attempts
+            ),
+            KingdomGeneratorError::NoCardData(set_ids) => write!(
+                f,
+                "There is no card data for set ids {}.",
+                set_ids.iter().map(|set_id| set_id.to_string()).collect::<Vec<String>>().join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KingdomGeneratorError {}
+
+pub fn generate_kingdom<R: Rng>(card_data: &CardData, rng: &mut R) -> Result<Kingdom, KingdomGeneratorError> {
+    generate_filtered_kingdom(card_data, &KingdomFilter::default(), rng)
+}
+
+pub fn generate_filtered_kingdom<R: Rng>(
+    card_data: &CardData,
+    filter: &KingdomFilter,
+    rng: &mut R
+) -> Result<Kingdom, KingdomGeneratorError> {
+    // a set without card data would otherwise just leave too few cards to build from
+    let set_ids_without_cards: Vec<u8> = filter.set_ids.iter()
+        .filter(|set_id| !card_data.set_index.contains_key(set_id))
+        .copied()
+        .collect();
+    if !set_ids_without_cards.is_empty() {
+        return Err(KingdomGeneratorError::NoCardData(set_ids_without_cards));
+    }
+
+    let pool: Vec<&Card> = kingdom_card_pool(card_data).into_iter()
+        .filter(|card| filter.allows(card))
+        .collect();
+    let supply_cards = pool.len();
+    let mut kingdom = generate_kingdom_from_pool(pool, &filter.require_tags, basic_card_ids(card_data), rng)?;
+
+    let landscapes = landscape_pool(card_data).into_iter()
+        .filter(|landscape| filter.allows_landscape(landscape))
+        .collect();
+    let has_liaison = kingdom.supply_card_ids.iter()
+        .any(|id| card_data.id_card_map[id].card_types.contains(&CardType::Liaison));
+    kingdom.landscape_ids = choose_landscapes(landscapes, supply_cards, has_liaison, rng);
+    Ok(kingdom)
+}
+
+pub fn generate_kingdom_from_seed(card_data: &CardData, seed: u64) -> Result<Kingdom, KingdomGeneratorError> {
+    generate_filtered_kingdom_from_seed(card_data, &KingdomFilter::default(), seed)
+}
+
+pub fn generate_filtered_kingdom_from_seed(
+    card_data: &CardData,
+    filter: &KingdomFilter,
+    seed: u64
+) -> Result<Kingdom, KingdomGeneratorError> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    match filter.synergy_attempts {
+        Some(attempts) => generate_synergy_weighted_kingdom(card_data, filter, &mut rng, attempts),
+        None => generate_filtered_kingdom(card_data, filter, &mut rng),
+    }
+}
+
+/// Generates `attempts` kingdoms and keeps the one with the highest interaction score.
+pub fn generate_synergy_weighted_kingdom<R: Rng>(
+    card_data: &CardData,
+    filter: &KingdomFilter,
+    rng: &mut R,
+    attempts: usize
+) -> Result<Kingdom, KingdomGeneratorError> {
+    if !(1..=MAX_SYNERGY_ATTEMPTS).contains(&attempts) {
+        return Err(KingdomGeneratorError::InvalidSynergyAttempts(attempts));
+    }
+    let mut best = generate_filtered_kingdom(card_data, filter, rng)?;
+    let mut best_score = score_kingdom(&best, card_data).interaction_score;
+    for _ in 1..attempts {
+        let kingdom = generate_filtered_kingdom(card_data, filter, rng)?;
+        let score = score_kingdom(&kingdom, card_data).interaction_score;
+        if score > best_score {
+            best = kingdom;
+            best_score = score;
+        }
+    }
+    Ok(best)
+}
+
+pub(crate) fn generate_kingdom_from_pool<R: Rng>(
+    mut candidates: Vec<&Card>,
+    require_tags: &[CardTag],
+    basic_card_ids: Vec<u16>,
+    rng: &mut R
+) -> Result<Kingdom, KingdomGeneratorError> {
+    let available = candidates.len();
+    // the pool must be in a stable order before shuffling for a seed to be reproducible
+    candidates.sort_by_key(|card| card.id);
+    candidates.shuffle(rng);
+
+    let mut chosen: Vec<&Card> = vec![];
+    while chosen.len() < KINGDOM_SIZE {
+        let missing_tags = missing_tags(&chosen, require_tags);
+        if let Some(card_tag) = missing_tags.first() {
+            match candidates.iter().position(|card| card.card_tags.contains(card_tag)) {
+                Some(position) => chosen.push(candidates.remove(position)),
+                None => return Err(KingdomGeneratorError::NoCardWithTag(*card_tag)),
+            }
+            continue;
+        }
+
+        let unmet = unmet_requirements(&chosen);
+        let next = match unmet.first() {
+            Some((card_id, requirement)) => {
+                match candidates.iter().position(|card| card_has_tags(card, requirement)) {
+                    Some(position) => position,
+                    None => {
+                        let card_id = *card_id;
+                        chosen.retain(|card| card.id != card_id);
+                        continue;
+                    }
+                }
+            },
+            None if candidates.is_empty() => {
+                return Err(KingdomGeneratorError::NotEnoughCards { required: KINGDOM_SIZE, available });
+            },
+            None => 0,
+        };
+
+        let mut trial = chosen.clone();
+        trial.push(candidates.remove(next));
+        if trial.len() + unmet_requirements(&trial).len() <= KINGDOM_SIZE {
+            chosen = trial;
+        }
+    }
+
+    if let Some(card_tag) = missing_tags(&chosen, require_tags).first() {
+        return Err(KingdomGeneratorError::NoCardWithTag(*card_tag));
+    }
+
+    // supply cards are laid out cheapest first
+    chosen.sort_by(|card, other| card.cost.cmp(&other.cost).then_with(|| card.name.cmp(&other.name)));
+    let supply_card_ids: Vec<u16> = chosen.iter().map(|card| card.id).collect();
+    Ok(Kingdom { supply_card_ids, basic_card_ids, landscape_ids: vec![] })
+}
+
+/// Deals from a randomizer deck holding the supply cards and the landscapes until the kingdom has its supply cards,
+/// every landscape dealt on the way is used up to `MAX_LANDSCAPES`.
+/// Allies are not in the randomizer deck, a kingdom with a Liaison gets one on top of the other landscapes.
+pub(crate) fn choose_landscapes<R: Rng>(
+    mut landscapes: Vec<&Landscape>,
+    supply_cards: usize,
+    has_liaison: bool,
+    rng: &mut R
+) -> Vec<u16> {
+    landscapes.sort_by_key(|landscape| landscape.id);
+    let (randomizers, allies): (Vec<&Landscape>, Vec<&Landscape>) = landscapes.into_iter()
+        .partition(|landscape| landscape.is_randomizer());
+
+    let mut deck: Vec<Option<&Landscape>> = vec![None; supply_cards];
+    deck.extend(randomizers.into_iter().map(Some));
+    deck.shuffle(rng);
+
+    let mut landscape_ids = vec![];
+    let mut dealt_supply_cards = 0;
+    for randomizer in deck {
+        match randomizer {
+            _ if dealt_supply_cards == KINGDOM_SIZE => break,
+            Some(landscape) if landscape_ids.len() < MAX_LANDSCAPES => landscape_ids.push(landscape.id),
+            Some(_) => {},
+            None => dealt_supply_cards += 1,
+        }
+    }
+
+    if has_liaison {
+        if let Some(ally) = allies.choose(rng) {
+            landscape_ids.push(ally.id);
+        }
+    }
+    landscape_ids
+}
+
+/// Cards that only come into the game with another card, such as Plunder or the Ruins, are never dealt as kingdom cards.
+pub(crate) fn kingdom_card_pool(card_data: &CardData) -> Vec<&Card> {
+    let added_card_ids: HashSet<u16> = card_data.id_card_map.values()
+        .flat_map(|card| {
+            let relationships = &card.relationships;
+            relationships.setup_card_ids.iter()
+                .chain(relationships.pile_card_ids.iter().filter(move |id| **id != card.id))
+                .copied()
+        })
+        .collect();
+    let mut pool: Vec<&Card> = card_data.id_card_map.values()
+        .filter(|card| card.supply_card && !card.basic_card && !added_card_ids.contains(&card.id))
+        .collect();
+    pool.sort_by_key(|card| card.id);
+    pool
+}
+
+pub(crate) fn landscape_pool(card_data: &CardData) -> Vec<&Landscape> {
+    let mut pool: Vec<&Landscape> = card_data.id_landscape_map.values().collect();
+    pool.sort_by_key(|landscape| landscape.id);
+    pool
+}
+
+pub(crate) fn basic_card_ids(card_data: &CardData) -> Vec<u16> {
+    let mut ids: Vec<u16> = card_data.id_card_map.values()
+        .filter(|card| card.basic_card)
+        .map(|card| card.id)
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+/// Every kingdom requirement that no other card in `cards` satisfies, paired with the id of the card that needs it.
+pub(crate) fn unmet_requirements<'a>(cards: &[&'a Card]) -> Vec<(u16, &'a Vec<CardTag>)> {
+    let mut unmet = vec![];
+    for card in cards {
+        for requirement in card.kingdom_requirements.iter() {
+            let met = cards.iter().any(|other| other.id != card.id && card_has_tags(other, requirement));
+            if !met {
+                unmet.push((card.id, requirement));
+            }
+        }
+    }
+    unmet
+}
+
+fn missing_tags(cards: &[&Card], require_tags: &[CardTag]) -> Vec<CardTag> {
+    require_tags.iter()
+        .filter(|tag| !cards.iter().any(|card| card.card_tags.contains(tag)))
+        .copied()
+        .collect()
+}
+
+pub(crate) fn card_has_tags(card: &Card, tags: &[CardTag]) -> bool {
+    tags.iter().all(|tag| card.card_tags.contains(tag))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{
+        card_relationships::CardRelationships,
+        data_loaders::card_data_loader::get_all_card_vec,
+        landscape::LandscapeType,
+        state::card_data::{build_card_data, build_card_data_from_cards}
+    };
+
+    fn cards_from_names<'a>(card_data: &'a CardData, names: &[&str]) -> Vec<&'a Card> {
+        names.iter().map(|name| &card_data.name_card_map[*name]).collect()
+    }
+
+    #[test]
+    fn test_generate_kingdom_has_ten_unique_supply_cards() {
+        let card_data = build_card_data();
+        let kingdom = generate_kingdom_from_seed(&card_data, 1).unwrap();
+        let mut ids = kingdom.supply_card_ids.clone();
+        ids.sort_unstable();
+        ids.dedup();
+
+        assert_eq!(ids.len(), KINGDOM_SIZE);
+        for id in kingdom.supply_card_ids.iter() {
+            let card = &card_data.id_card_map[id];
+            assert!(card.supply_card);
+            assert!(!card.basic_card);
+        }
+    }
+
+    #[test]
+    fn test_generate_kingdom_adds_basic_cards() {
+        let card_data = build_card_data();
+        let kingdom = generate_kingdom_from_seed(&card_data, 1).unwrap();
+        assert_eq!(kingdom.basic_card_ids, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_generate_kingdom_same_seed_same_kingdom() {
+        let card_data = build_card_data();
+        for seed in 0..20 {
+            assert_eq!(
+                generate_kingdom_from_seed(&card_data, seed).unwrap(),
+                generate_kingdom_from_seed(&card_data, seed).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_kingdom_different_seed_different_kingdom() {
+        let card_data = build_card_data();
+        assert_ne!(
+            generate_kingdom_from_seed(&card_data, 1).unwrap(),
+            generate_kingdom_from_seed(&card_data, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_kingdom_meets_requirements() {
+        let card_data = build_card_data();
+        for seed in 0..200 {
+            let kingdom = generate_kingdom_from_seed(&card_data, seed).unwrap();
+            let cards: Vec<&Card> = kingdom.supply_card_ids.iter().map(|id| &card_data.id_card_map[id]).collect();
+            assert!(unmet_requirements(&cards).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_generate_kingdom_from_pool_moat_needs_attack() {
+        let card_data = build_card_data();
+        let moat = card_data.name_card_map["Moat"].id;
+        let militia = card_data.name_card_map["Militia"].id;
+        let pool = cards_from_names(&card_data, &[
+            "Moat", "Militia", "Cellar", "Chapel", "Village", "Workshop",
+            "Smithy", "Festival", "Laboratory", "Market", "Library", "Council Room"
+        ]);
+        let mut saw_moat = false;
+        for seed in 0..100 {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            let kingdom = generate_kingdom_from_pool(pool.clone(), &[], vec![], &mut rng).unwrap();
+            if kingdom.supply_card_ids.contains(&moat) {
+                saw_moat = true;
+                assert!(kingdom.supply_card_ids.contains(&militia));
+            }
+        }
+        assert!(saw_moat);
+    }
+
+    #[test]
+    fn test_generate_kingdom_from_pool_drops_unsatisfiable_card() {
+        let card_data = build_card_data();
+        let pool = cards_from_names(&card_data, &[
+            "Moat", "Cellar", "Chapel", "Village", "Workshop", "Smithy",
+            "Festival", "Laboratory", "Market", "Library", "Council Room"
+        ]);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let kingdom = generate_kingdom_from_pool(pool, &[], vec![], &mut rng).unwrap();
+        assert!(!kingdom.supply_card_ids.contains(&card_data.name_card_map["Moat"].id));
+    }
+
+    #[test]
+    fn test_generate_kingdom_from_pool_not_enough_cards() {
+        let card_data = build_card_data();
+        let pool = cards_from_names(&card_data, &["Cellar", "Chapel", "Village"]);
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        assert_eq!(
+            generate_kingdom_from_pool(pool, &[], vec![], &mut rng),
+            Err(KingdomGeneratorError::NotEnoughCards { required: 10, available: 3 })
+        );
+    }
+
+    #[test]
+    fn test_generate_synergy_weighted_kingdom_scores_at_least_first_attempt() {
+        let card_data = build_card_data();
+        for seed in 0..20 {
+            let first = generate_kingdom(&card_data, &mut ChaCha8Rng::seed_from_u64(seed)).unwrap();
+            let best = generate_synergy_weighted_kingdom(&card_data, &KingdomFilter::default(), &mut ChaCha8Rng::seed_from_u64(seed), 10).unwrap();
+            assert!(
+                score_kingdom(&best, &card_data).interaction_score >=
+                score_kingdom(&first, &card_data).interaction_score
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_synergy_weighted_kingdom_same_seed_same_kingdom() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter { synergy_attempts: Some(10), ..Default::default() };
+        assert_eq!(
+            generate_filtered_kingdom_from_seed(&card_data, &filter, 7).unwrap(),
+            generate_synergy_weighted_kingdom(&card_data, &KingdomFilter::default(), &mut ChaCha8Rng::seed_from_u64(7), 10).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_synergy_weighted_kingdom_attempts() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter { set_ids: vec![1], ..Default::default() };
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let kingdom = generate_synergy_weighted_kingdom(&card_data, &filter, &mut rng, 5).unwrap();
+        for id in kingdom.supply_card_ids.iter() {
+            assert_eq!(card_data.id_card_map[id].set_id, 1);
+        }
+        assert_eq!(
+            generate_synergy_weighted_kingdom(&card_data, &filter, &mut rng, 0),
+            Err(KingdomGeneratorError::InvalidSynergyAttempts(0))
+        );
+        assert_eq!(
+            generate_synergy_weighted_kingdom(&card_data, &filter, &mut rng, MAX_SYNERGY_ATTEMPTS + 1),
+            Err(KingdomGeneratorError::InvalidSynergyAttempts(101))
+        );
+        assert_eq!(
+            KingdomGeneratorError::InvalidSynergyAttempts(0).to_string(),
+            "Synergy attempts must be between 1 and 100, got 0."
+        );
+    }
+
+    #[test]
+    fn test_generate_filtered_kingdom_set_ids() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter { set_ids: vec![1], ..Default::default() };
+        let kingdom = generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(1)).unwrap();
+        for id in kingdom.supply_card_ids.iter() {
+            assert_eq!(card_data.id_card_map[id].set_id, 1);
+        }
+
+        let filter = KingdomFilter { set_ids: vec![1, 9, 10], ..Default::default() };
+        assert_eq!(
+            generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(1)),
+            Err(KingdomGeneratorError::NoCardData(vec![9, 10]))
+        );
+
+        let filter = KingdomFilter { set_ids: vec![1], min_coins: Some(6), ..Default::default() };
+        assert!(matches!(
+            generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(1)),
+            Err(KingdomGeneratorError::NotEnoughCards { required: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn test_generate_filtered_kingdom_require_tags() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter { require_tags: vec![CardTag::TrashFromHand, CardTag::CurseAttack], ..Default::default() };
+        for seed in 0..50 {
+            let kingdom = generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(seed)).unwrap();
+            let cards: Vec<&Card> = kingdom.supply_card_ids.iter().map(|id| &card_data.id_card_map[id]).collect();
+            assert_eq!(kingdom.supply_card_ids.len(), KINGDOM_SIZE);
+            assert!(missing_tags(&cards, &filter.require_tags).is_empty());
+            assert!(unmet_requirements(&cards).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_generate_filtered_kingdom_exclude_tags() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter { exclude_tags: vec![CardTag::CurseAttack, CardTag::IsAttack], ..Default::default() };
+        for seed in 0..50 {
+            let kingdom = generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(seed)).unwrap();
+            for id in kingdom.supply_card_ids.iter() {
+                assert!(filter.allows(&card_data.id_card_map[id]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_kingdom_sorted_by_cost() {
+        let card_data = build_card_data();
+        for seed in 0..20 {
+            let kingdom = generate_kingdom_from_seed(&card_data, seed).unwrap();
+            let cards: Vec<&Card> = kingdom.supply_card_ids.iter().map(|id| &card_data.id_card_map[id]).collect();
+            for pair in cards.windows(2) {
+                assert!((&pair[0].cost, &pair[0].name) <= (&pair[1].cost, &pair[1].name));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_filtered_kingdom_coins() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter { min_coins: Some(3), max_coins: Some(5), ..Default::default() };
+        for seed in 0..20 {
+            let kingdom = generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(seed)).unwrap();
+            for id in kingdom.supply_card_ids.iter() {
+                let coins = card_data.id_card_map[id].cost.coins;
+                assert!((3..=5).contains(&coins));
+            }
+        }
+    }
+
+    #[test]
+    fn test_kingdom_filter_allows_cost() {
+        let card_data = build_card_data();
+        let mut card = card_data.name_card_map["Chapel"].clone();
+        assert!(KingdomFilter { exclude_potion: true, exclude_debt: true, ..Default::default() }.allows(&card));
+        assert!(!KingdomFilter { min_coins: Some(3), ..Default::default() }.allows(&card));
+        assert!(!KingdomFilter { max_coins: Some(1), ..Default::default() }.allows(&card));
+
+        card.cost.potion = true;
+        assert!(!KingdomFilter { exclude_potion: true, ..Default::default() }.allows(&card));
+        card.cost = Cost { debt: 8, ..Default::default() };
+        assert!(!KingdomFilter { exclude_debt: true, ..Default::default() }.allows(&card));
+        assert!(KingdomFilter { exclude_potion: true, ..Default::default() }.allows(&card));
+    }
+
+    #[test]
+    fn test_generate_filtered_kingdom_required_tag_excluded() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter {
+            require_tags: vec![CardTag::CurseAttack],
+            exclude_tags: vec![CardTag::IsAttack],
+            ..Default::default()
+        };
+        assert_eq!(
+            generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(1)),
+            Err(KingdomGeneratorError::NoCardWithTag(CardTag::CurseAttack))
+        );
+    }
+
+    #[test]
+    fn test_generate_kingdom_landscapes() {
+        let card_data = build_card_data();
+        let mut saw_landscape = false;
+        for seed in 0..50 {
+            let kingdom = generate_kingdom_from_seed(&card_data, seed).unwrap();
+            assert!(kingdom.landscape_ids.len() <= MAX_LANDSCAPES);
+            saw_landscape |= !kingdom.landscape_ids.is_empty();
+            for id in kingdom.landscape_ids.iter() {
+                assert!(card_data.id_landscape_map[id].is_randomizer());
+            }
+        }
+        assert!(saw_landscape);
+    }
+
+    #[test]
+    fn test_generate_filtered_kingdom_landscapes_follow_set_ids() {
+        let card_data = build_card_data();
+        let filter = KingdomFilter { set_ids: vec![1], ..Default::default() };
+        for seed in 0..20 {
+            let kingdom = generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(seed)).unwrap();
+            assert!(kingdom.landscape_ids.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_generate_filtered_kingdom_owned_editions() {
+        let card_data = build_card_data();
+        let mut owned_editions = OwnedEditions::default();
+        owned_editions.add(1, 2);
+        let filter = KingdomFilter { owned_editions, ..Default::default() };
+        for seed in 0..20 {
+            let kingdom = generate_filtered_kingdom(&card_data, &filter, &mut ChaCha8Rng::seed_from_u64(seed)).unwrap();
+            for id in kingdom.supply_card_ids.iter() {
+                let card = &card_data.id_card_map[id];
+                assert_eq!(card.set_id, 1);
+                assert!(card.editions.contains(&2));
+                assert_ne!(card.name, "Adventurer");
+            }
+            assert!(kingdom.landscape_ids.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_kingdom_filter_allows_owned_editions() {
+        let card_data = build_card_data();
+        let mut owned_editions = OwnedEditions::default();
+        owned_editions.add(1, 1);
+        owned_editions.add(10, 1);
+        let filter = KingdomFilter { owned_editions, ..Default::default() };
+        assert!(filter.allows(&card_data.name_card_map["Adventurer"]));
+        assert!(filter.allows(&card_data.name_card_map["Village"]));
+        assert!(!filter.allows(&card_data.name_card_map["Artisan"]));
+        assert!(!filter.allows(&card_data.name_card_map["Courtyard"]));
+        assert!(filter.allows_landscape(&card_data.id_landscape_map[&31000]));
+        assert!(!filter.allows_landscape(&card_data.id_landscape_map[&31100]));
+    }
+
+    #[test]
+    fn test_choose_landscapes_at_most_two() {
+        let card_data = build_card_data();
+        let landscapes = landscape_pool(&card_data);
+        for seed in 0..50 {
+            // far more landscapes than supply cards deals landscapes nearly every time
+            let landscape_ids = choose_landscapes(landscapes.clone(), 10, false, &mut ChaCha8Rng::seed_from_u64(seed));
+            assert!(landscape_ids.len() <= MAX_LANDSCAPES);
+        }
+        assert_eq!(choose_landscapes(landscapes, 10, false, &mut ChaCha8Rng::seed_from_u64(1)).len(), MAX_LANDSCAPES);
+    }
+
+    #[test]
+    fn test_choose_landscapes_ally_needs_liaison() {
+        let card_data = build_card_data();
+        let allies: Vec<&Landscape> = landscape_pool(&card_data).into_iter()
+            .filter(|landscape| landscape.landscape_type == LandscapeType::Ally)
+            .collect();
+        assert!(choose_landscapes(allies.clone(), 10, false, &mut ChaCha8Rng::seed_from_u64(1)).is_empty());
+
+        let landscape_ids = choose_landscapes(allies, 10, true, &mut ChaCha8Rng::seed_from_u64(1));
+        assert_eq!(landscape_ids.len(), 1);
+        assert_eq!(card_data.id_landscape_map[&landscape_ids[0]].landscape_type, LandscapeType::Ally);
+    }
+
+    #[test]
+    fn test_choose_landscapes_few_landscapes() {
+        let card_data = build_card_data();
+        let landscapes: Vec<&Landscape> = landscape_pool(&card_data).into_iter().take(1).collect();
+        let mut landscape_counts = [0; 2];
+        for seed in 0..50 {
+            let landscape_ids = choose_landscapes(landscapes.clone(), 30, false, &mut ChaCha8Rng::seed_from_u64(seed));
+            landscape_counts[landscape_ids.len()] += 1;
+        }
+        // one landscape among 30 supply cards is dealt before the tenth supply card about a third of the time
+        assert!(landscape_counts[0] > 0 && landscape_counts[1] > 0);
+    }
+
+    #[test]
+    fn test_choose_landscapes_no_landscapes() {
+        assert!(choose_landscapes(vec![], 30, true, &mut ChaCha8Rng::seed_from_u64(1)).is_empty());
+    }
+
+    #[test]
+    fn test_kingdom_card_pool_skips_added_cards() {
+        let mut cards = get_all_card_vec();
+        let mut encampment = cards.iter().find(|card| card.name == "Chapel").unwrap().clone();
+        encampment.id = 1100;
+        encampment.name = "Encampment".to_string();
+        encampment.relationships = CardRelationships { setup_card_ids: vec![102], pile_card_ids: vec![1100, 103], heirloom_card_id: None };
+        cards.push(encampment);
+        let card_data = build_card_data_from_cards(cards, vec![]);
+
+        let pool_ids: Vec<u16> = kingdom_card_pool(&card_data).iter().map(|card| card.id).collect();
+        assert!(pool_ids.contains(&1100));
+        assert!(pool_ids.contains(&101));
+        assert!(!pool_ids.contains(&102));
+        assert!(!pool_ids.contains(&103));
+    }
+
+    #[test]
+    fn test_unmet_requirements() {
+        let card_data = build_card_data();
+        let moat = &card_data.name_card_map["Moat"];
+        let cards = cards_from_names(&card_data, &["Moat", "Village"]);
+        assert_eq!(unmet_requirements(&cards), vec![(moat.id, &moat.kingdom_requirements[0])]);
+
+        let cards = cards_from_names(&card_data, &["Moat", "Witch"]);
+        assert!(unmet_requirements(&cards).is_empty());
+    }
+
+    #[test]
+    fn test_error_fmt() {
+        let error = KingdomGeneratorError::NotEnoughCards { required: 10, available: 3 };
+        assert_eq!(
+            format!("{error}"),
+            "Not enough cards to build a kingdom, 10 required but only 3 could be used.");
+
+        let error = KingdomGeneratorError::NoCardWithTag(CardTag::CurseAttack);
+        assert_eq!(
+            format!("{error}"),
+            "No card with tag CurseAttack could be added to the kingdom.");
+
+        let error = KingdomGeneratorError::NoCardData(vec![9, 10]);
+        assert_eq!(
+            format!("{error}"),
+            "There is no card data for set ids 9, 10.");
+    }
+}
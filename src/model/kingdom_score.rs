@@ -0,0 +1,142 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use super::{card::Card, card_tag::CardTag, kingdom::Kingdom, kingdom_generator::card_has_tags, state::card_data::CardData};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CardInteraction {
+    pub card_id: u16,
+    pub other_card_id: u16,
+    pub card_tags: Vec<CardTag>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct KingdomScore {
+    pub interaction_score: i32,
+    pub synergies: Vec<CardInteraction>,
+    pub anti_synergies: Vec<CardInteraction>,
+}
+
+/// Scores the supply cards of a kingdom against each other.
+/// Each matched synergy tag group adds one point and each matched anti synergy tag group removes one.
+/// Basic cards are in every kingdom so they are left out of the score.
+pub fn score_kingdom(kingdom: &Kingdom, card_data: &CardData) -> KingdomScore {
+    let cards: Vec<&Card> = kingdom.supply_card_ids.iter()
+        .filter_map(|id| card_data.id_card_map.get(id))
+        .collect();
+
+    let synergies = find_interactions(&cards, |card| &card.kingdom_synergies);
+    let anti_synergies = find_interactions(&cards, |card| &card.kingdom_anti_synergies);
+
+    KingdomScore {
+        interaction_score: synergies.len() as i32 - anti_synergies.len() as i32,
+        synergies,
+        anti_synergies
+    }
+}
+
+fn find_interactions(cards: &[&Card], tag_groups: fn(&Card) -> &Vec<Vec<CardTag>>) -> Vec<CardInteraction> {
+    let mut interactions = vec![];
+    for card in cards {
+        for other in cards {
+            if card.id == other.id {
+                continue;
+            }
+            for card_tags in tag_groups(card) {
+                if card_has_tags(other, card_tags) {
+                    interactions.push(CardInteraction {
+                        card_id: card.id,
+                        other_card_id: other.id,
+                        card_tags: card_tags.clone()
+                    });
+                }
+            }
+        }
+    }
+    interactions
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+    use crate::model::state::card_data::build_card_data;
+
+    fn kingdom_from_names(card_data: &CardData, names: &[&str]) -> Kingdom {
+        Kingdom {
+            supply_card_ids: names.iter().map(|name| card_data.name_card_map[*name].id).collect(),
+            basic_card_ids: vec![0, 1, 2, 3, 4, 5, 6],
+            landscape_ids: vec![]
+        }
+    }
+
+    #[test]
+    fn test_score_kingdom_no_interactions() {
+        let card_data = build_card_data();
+        let kingdom = kingdom_from_names(&card_data, &["Chapel", "Village", "Smithy"]);
+        let score = score_kingdom(&kingdom, &card_data);
+        assert_eq!(score.interaction_score, 0);
+        assert!(score.synergies.is_empty());
+        assert!(score.anti_synergies.is_empty());
+    }
+
+    #[test]
+    fn test_score_kingdom_synergy() {
+        let card_data = build_card_data();
+        let kingdom = kingdom_from_names(&card_data, &["Witch", "Chapel"]);
+        let score = score_kingdom(&kingdom, &card_data);
+        assert_eq!(score.interaction_score, 1);
+        assert_eq!(score.synergies, vec![CardInteraction {
+            card_id: card_data.name_card_map["Witch"].id,
+            other_card_id: card_data.name_card_map["Chapel"].id,
+            card_tags: vec![CardTag::TrashCurse]
+        }]);
+    }
+
+    #[test]
+    fn test_score_kingdom_matches_each_tag_group() {
+        let card_data = build_card_data();
+        let kingdom = kingdom_from_names(&card_data, &["Throne Room", "Festival", "Artisan"]);
+        let score = score_kingdom(&kingdom, &card_data);
+        assert_eq!(score.interaction_score, 2);
+        assert_eq!(score.synergies[0].card_tags, vec![CardTag::IsAction, CardTag::Costs5]);
+        assert_eq!(score.synergies[1].card_tags, vec![CardTag::IsAction, CardTag::Costs6]);
+    }
+
+    #[test]
+    fn test_score_kingdom_anti_synergy() {
+        let mut card_data = build_card_data();
+        let militia_id = card_data.name_card_map["Militia"].id;
+        let chapel_id = card_data.name_card_map["Chapel"].id;
+        card_data.id_card_map.get_mut(&chapel_id).unwrap().card_tags.push(CardTag::DiscardingAttack);
+
+        let kingdom = kingdom_from_names(&card_data, &["Militia", "Chapel"]);
+        let score = score_kingdom(&kingdom, &card_data);
+        assert_eq!(score.interaction_score, -1);
+        assert_eq!(score.anti_synergies, vec![CardInteraction {
+            card_id: militia_id,
+            other_card_id: chapel_id,
+            card_tags: vec![CardTag::DiscardingAttack]
+        }]);
+    }
+
+    #[test]
+    fn test_score_kingdom_ignores_unknown_ids() {
+        let card_data = build_card_data();
+        let kingdom = Kingdom { supply_card_ids: vec![65500], basic_card_ids: vec![], landscape_ids: vec![] };
+        assert_eq!(score_kingdom(&kingdom, &card_data).interaction_score, 0);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let score = KingdomScore {
+            interaction_score: 1,
+            synergies: vec![CardInteraction { card_id: 129, other_card_id: 101, card_tags: vec![CardTag::TrashCurse] }],
+            anti_synergies: vec![]
+        };
+        assert_eq!(
+            serde_json::to_string(&score).unwrap(),
+            "{\"interaction_score\":1,\"synergies\":[{\"card_id\":129,\"other_card_id\":101,\"card_tags\":[\"TrashCurse\"]}],\"anti_synergies\":[]}");
+    }
+}
@@ -0,0 +1,78 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use super::cost::Cost;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub enum LandscapeType {
+    Event,
+    Landmark,
+    Project,
+    Way,
+    Ally,
+    Trait,
+}
+
+/// A card laid out beside the supply, it is never bought as a card and has no pile.
+/// Landscape ids come from `landscape_id_range` of their set so they never refer to a `Card`.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Landscape {
+    pub id: u16,
+    pub name: String,
+    pub landscape_type: LandscapeType,
+    pub set_id: u8,
+    pub editions: Vec<u8>,
+    // only Events and Projects can be bought
+    #[serde(default)]
+    pub cost: Option<Cost>,
+    pub art_url: Vec<String>,
+}
+
+impl Landscape {
+    /// Allies are only used when a Liaison is in the kingdom, every other type is dealt from the randomizer deck.
+    pub fn is_randomizer(&self) -> bool {
+        self.landscape_type != LandscapeType::Ally
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    fn alms() -> Landscape {
+        Landscape {
+            id: 31000,
+            name: "Alms".to_string(),
+            landscape_type: LandscapeType::Event,
+            set_id: 10,
+            editions: vec![1],
+            cost: Some(Cost::from_coins(0)),
+            art_url: vec!["www.image1.com".to_string()]
+        }
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&alms()).unwrap(),
+            "{\"id\":31000,\"name\":\"Alms\",\"landscape_type\":\"Event\",\"set_id\":10,\"editions\":[1],\"cost\":{\"coins\":0,\"potion\":false,\"debt\":0,\"modifiers\":[]},\"art_url\":[\"www.image1.com\"]}");
+    }
+
+    #[test]
+    fn test_deserialize_without_cost() {
+        let json = "{\"id\":31100,\"name\":\"Aqueduct\",\"landscape_type\":\"Landmark\",\"set_id\":11,\"editions\":[1],\"art_url\":[]}";
+        let landscape: Landscape = serde_json::from_str(json).unwrap();
+        assert_eq!(landscape.landscape_type, LandscapeType::Landmark);
+        assert_eq!(landscape.cost, None);
+    }
+
+    #[test]
+    fn test_is_randomizer() {
+        let mut landscape = alms();
+        assert!(landscape.is_randomizer());
+        landscape.landscape_type = LandscapeType::Ally;
+        assert!(!landscape.is_randomizer());
+    }
+}
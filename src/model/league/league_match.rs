@@ -0,0 +1,103 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::model::kingdom::Kingdom;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Participant {
+    pub player_name: String,
+    pub victory_points: i16,
+    pub turns: u8,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Match {
+    pub id: u32,
+    pub season_id: u16,
+    pub kingdom: Kingdom,
+    pub participants: Vec<Participant>,
+}
+
+impl Participant {
+    /// Most victory points wins, ties are broken by fewest turns taken.
+    pub fn beats(&self, other: &Participant) -> bool {
+        self.victory_points > other.victory_points ||
+        (self.victory_points == other.victory_points && self.turns < other.turns)
+    }
+}
+
+impl Match {
+    /// The 1 based finishing place of each participant, in participant order.
+    /// Participants that are still tied after the turn count share a place.
+    pub fn placements(&self) -> Vec<u8> {
+        self.participants.iter()
+            .map(|participant| {
+                1 + self.participants.iter().filter(|other| other.beats(participant)).count() as u8
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    fn participant(player_name: &str, victory_points: i16, turns: u8) -> Participant {
+        Participant { player_name: player_name.to_string(), victory_points, turns }
+    }
+
+    fn league_match(participants: Vec<Participant>) -> Match {
+        Match {
+            id: 1,
+            season_id: 1,
+            kingdom: Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] },
+            participants
+        }
+    }
+
+    #[test]
+    fn test_placements() {
+        let played = league_match(vec![
+            participant("A", 20, 15),
+            participant("B", 35, 15),
+            participant("C", -1, 15),
+        ]);
+        assert_eq!(played.placements(), vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn test_placements_tie_broken_by_turns() {
+        let played = league_match(vec![
+            participant("A", 30, 16),
+            participant("B", 30, 15),
+        ]);
+        assert_eq!(played.placements(), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_placements_shared() {
+        let played = league_match(vec![
+            participant("A", 30, 15),
+            participant("B", 30, 15),
+            participant("C", 10, 15),
+        ]);
+        assert_eq!(played.placements(), vec![1, 1, 3]);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let played = league_match(vec![participant("A", 30, 15)]);
+        assert_eq!(
+            serde_json::to_string(&played).unwrap(),
+            "{\"id\":1,\"season_id\":1,\"kingdom\":{\"supply_card_ids\":[101],\"basic_card_ids\":[0],\"landscape_ids\":[]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15}]}");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let json = "{\"id\":1,\"season_id\":1,\"kingdom\":{\"supply_card_ids\":[101],\"basic_card_ids\":[0],\"landscape_ids\":[]},\"participants\":[{\"player_name\":\"A\",\"victory_points\":30,\"turns\":15}]}";
+        let played: Match = serde_json::from_str(json).unwrap();
+        assert_eq!(played, league_match(vec![participant("A", 30, 15)]));
+    }
+}
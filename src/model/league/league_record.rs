@@ -0,0 +1,162 @@
+use rocket::serde::{Deserialize, Serialize};
+
+use crate::model::kingdom::Kingdom;
+
+use super::{
+    league_match::{Match, Participant},
+    player::Player,
+    rating::{RatingEngine, RatingHistory},
+    season::Season,
+    standing::{Standing, build_standings}
+};
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct League {
+    pub players: Vec<Player>,
+    pub seasons: Vec<Season>,
+    pub matches: Vec<Match>,
+}
+
+impl League {
+    pub fn player(&self, name: &str) -> Option<&Player> {
+        self.players.iter().find(|player| player.name == name)
+    }
+
+    /// Adds the player if no player with that name exists yet.
+    pub fn add_player(&mut self, name: &str) -> &Player {
+        match self.players.iter().position(|player| player.name == name) {
+            Some(index) => &self.players[index],
+            None => {
+                self.players.push(Player { name: name.to_string() });
+                self.players.last().unwrap()
+            }
+        }
+    }
+
+    pub fn add_season(&mut self, name: &str) -> u16 {
+        let id = self.seasons.iter().map(|season| season.id).max().unwrap_or(0) + 1;
+        self.seasons.push(Season { id, name: name.to_string() });
+        id
+    }
+
+    /// Records a match, adding any new players, and returns the new match id.
+    pub fn add_match(&mut self, season_id: u16, kingdom: Kingdom, participants: Vec<Participant>) -> u32 {
+        for participant in participants.iter() {
+            self.add_player(&participant.player_name);
+        }
+        let id = self.matches.iter().map(|played| played.id).max().unwrap_or(0) + 1;
+        self.matches.push(Match { id, season_id, kingdom, participants });
+        id
+    }
+
+    /// Matches of a season in the order they were played.
+    pub fn season_matches(&self, season_id: u16) -> impl Iterator<Item = &Match> {
+        self.matches.iter().filter(move |played| played.season_id == season_id)
+    }
+
+    pub fn player_matches<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Match> {
+        self.matches.iter()
+            .filter(move |played| played.participants.iter().any(|participant| participant.player_name == name))
+    }
+
+    pub fn season_standings(&self, season_id: u16) -> Vec<Standing> {
+        build_standings(self.season_matches(season_id))
+    }
+
+    pub fn season_ratings(&self, season_id: u16, engine: &RatingEngine) -> Vec<RatingHistory> {
+        engine.rate_matches(self.season_matches(season_id))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    fn kingdom() -> Kingdom {
+        Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] }
+    }
+
+    fn participant(player_name: &str, victory_points: i16) -> Participant {
+        Participant { player_name: player_name.to_string(), victory_points, turns: 15 }
+    }
+
+    #[test]
+    fn test_add_player() {
+        let mut league = League::default();
+        league.add_player("A");
+        league.add_player("A");
+        league.add_player("B");
+        assert_eq!(league.players, vec![Player { name: "A".to_string() }, Player { name: "B".to_string() }]);
+        assert_eq!(league.player("B"), Some(&Player { name: "B".to_string() }));
+        assert_eq!(league.player("C"), None);
+    }
+
+    #[test]
+    fn test_add_season() {
+        let mut league = League::default();
+        assert_eq!(league.add_season("Spring"), 1);
+        assert_eq!(league.add_season("Summer"), 2);
+        assert_eq!(league.seasons[1], Season { id: 2, name: "Summer".to_string() });
+    }
+
+    #[test]
+    fn test_add_match() {
+        let mut league = League::default();
+        let season_id = league.add_season("Spring");
+        assert_eq!(league.add_match(season_id, kingdom(), vec![participant("A", 30), participant("B", 20)]), 1);
+        assert_eq!(league.add_match(season_id, kingdom(), vec![participant("B", 30), participant("C", 20)]), 2);
+        assert_eq!(league.players.len(), 3);
+        assert_eq!(league.matches[1].id, 2);
+    }
+
+    #[test]
+    fn test_season_matches_and_standings() {
+        let mut league = League::default();
+        let spring = league.add_season("Spring");
+        let summer = league.add_season("Summer");
+        league.add_match(spring, kingdom(), vec![participant("A", 30), participant("B", 20)]);
+        league.add_match(summer, kingdom(), vec![participant("B", 30), participant("A", 20)]);
+        league.add_match(summer, kingdom(), vec![participant("B", 30), participant("C", 20)]);
+
+        let summer_ids: Vec<u32> = league.season_matches(summer).map(|played| played.id).collect();
+        assert_eq!(summer_ids, vec![2, 3]);
+
+        let standings = league.season_standings(summer);
+        assert_eq!(standings[0].player_name, "B");
+        assert_eq!(standings[0].wins, 2);
+        assert_eq!(league.season_standings(spring)[0].player_name, "A");
+    }
+
+    #[test]
+    fn test_season_ratings() {
+        let mut league = League::default();
+        let spring = league.add_season("Spring");
+        let summer = league.add_season("Summer");
+        league.add_match(spring, kingdom(), vec![participant("A", 30), participant("B", 20)]);
+        league.add_match(summer, kingdom(), vec![participant("B", 30), participant("C", 20)]);
+
+        let ratings = league.season_ratings(summer, &RatingEngine::default());
+        assert_eq!(ratings.len(), 2);
+        assert_eq!(ratings[0].player_name, "B");
+        assert_eq!(ratings[0].current_rating(), Some(1516.0));
+    }
+
+    #[test]
+    fn test_player_matches() {
+        let mut league = League::default();
+        let season_id = league.add_season("Spring");
+        league.add_match(season_id, kingdom(), vec![participant("A", 30), participant("B", 20)]);
+        league.add_match(season_id, kingdom(), vec![participant("B", 30), participant("C", 20)]);
+        assert_eq!(league.player_matches("A").count(), 1);
+        assert_eq!(league.player_matches("B").count(), 2);
+        assert_eq!(league.player_matches("D").count(), 0);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let league = League::default();
+        assert_eq!(serde_json::to_string(&league).unwrap(), "{\"players\":[],\"seasons\":[],\"matches\":[]}");
+    }
+}
@@ -0,0 +1,96 @@
+use std::{fs::{self, create_dir_all}, io::ErrorKind, path::PathBuf};
+
+use rocket::serde::json::serde_json;
+
+use super::league_record::League;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+pub const DEFAULT_LEAGUE_STORE_PATH: &str = "league_data/league.json";
+
+/// Keeps the whole league in a single JSON file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LeagueStore {
+    pub path: PathBuf,
+}
+
+impl LeagueStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Loads the league, a store that has never been saved holds an empty league.
+    pub fn load(&self) -> Result<League> {
+        match fs::read_to_string(&self.path) {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(League::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Writes to a temporary file first so a failed save never leaves a half written league.
+    pub fn save(&self, league: &League) -> Result<()> {
+        if let Some(parent) = self.path.parent() { create_dir_all(parent)? }
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(league)?)?;
+        fs::rename(&temp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+impl Default for LeagueStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_LEAGUE_STORE_PATH)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs::remove_dir_all};
+
+    use super::*;
+    use crate::model::{kingdom::Kingdom, league::league_match::Participant};
+
+    fn test_store(name: &str) -> LeagueStore {
+        let folder = temp_dir().join(format!("dominion_league_store_{}_{}", name, std::process::id()));
+        let _ = remove_dir_all(&folder);
+        LeagueStore::new(folder.join("league.json"))
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty_league() {
+        let store = test_store("missing");
+        assert_eq!(store.load().unwrap(), League::default());
+    }
+
+    #[test]
+    fn test_save_then_load() {
+        let store = test_store("round_trip");
+        let mut league = League::default();
+        let season_id = league.add_season("Spring");
+        league.add_match(
+            season_id,
+            Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] },
+            vec![Participant { player_name: "A".to_string(), victory_points: 30, turns: 15 }]
+        );
+
+        store.save(&league).unwrap();
+        assert_eq!(store.load().unwrap(), league);
+        assert!(!store.path.with_extension("json.tmp").exists());
+        let _ = remove_dir_all(store.path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_load_malformed_file() {
+        let store = test_store("malformed");
+        create_dir_all(store.path.parent().unwrap()).unwrap();
+        fs::write(&store.path, "not a league").unwrap();
+        assert!(store.load().is_err());
+        let _ = remove_dir_all(store.path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(LeagueStore::default().path, PathBuf::from("league_data/league.json"));
+    }
+}
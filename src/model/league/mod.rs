@@ -0,0 +1,7 @@
+pub mod player;
+pub mod season;
+pub mod league_match;
+pub mod standing;
+pub mod league_record;
+pub mod league_store;
+pub mod rating;
@@ -0,0 +1,25 @@
+use rocket::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Player {
+    pub name: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    #[test]
+    fn test_serialize() {
+        let player = Player { name: "Mark".to_string() };
+        assert_eq!(serde_json::to_string(&player).unwrap(), "{\"name\":\"Mark\"}");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let player: Player = serde_json::from_str("{\"name\":\"Mark\"}").unwrap();
+        assert_eq!(player, Player { name: "Mark".to_string() });
+    }
+}
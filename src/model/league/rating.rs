@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use rocket::serde::{Deserialize, Serialize};
+
+use super::league_match::Match;
+
+pub const DEFAULT_RATING: f64 = 1500.0;
+pub const DEFAULT_K_FACTOR: f64 = 32.0;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RatingPoint {
+    pub match_id: u32,
+    pub rating: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RatingHistory {
+    pub player_name: String,
+    pub history: Vec<RatingPoint>,
+}
+
+impl RatingHistory {
+    pub fn current_rating(&self) -> Option<f64> {
+        self.history.last().map(|point| point.rating)
+    }
+}
+
+/// Multiplayer Elo, each game is scored as a round robin of head to head results.
+/// Finishing ahead of an opponent is a win, sharing a place is a draw.
+/// The K factor is split between the opponents so a game moves ratings as much as one 2 player game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatingEngine {
+    pub initial_rating: f64,
+    pub k_factor: f64,
+}
+
+impl Default for RatingEngine {
+    fn default() -> Self {
+        Self { initial_rating: DEFAULT_RATING, k_factor: DEFAULT_K_FACTOR }
+    }
+}
+
+impl RatingEngine {
+    pub fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+        1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+    }
+
+    /// New ratings for the participants of a game given their ratings and finishing places, lower places are better.
+    pub fn rate_game(&self, ratings: &[f64], placements: &[u8]) -> Vec<f64> {
+        let players = ratings.len();
+        if players < 2 {
+            return ratings.to_vec();
+        }
+        let k_factor = self.k_factor / (players - 1) as f64;
+
+        (0..players)
+            .map(|player| {
+                let change: f64 = (0..players)
+                    .filter(|opponent| *opponent != player)
+                    .map(|opponent| {
+                        let score = match placements[player].cmp(&placements[opponent]) {
+                            std::cmp::Ordering::Less => 1.0,
+                            std::cmp::Ordering::Equal => 0.5,
+                            std::cmp::Ordering::Greater => 0.0,
+                        };
+                        score - Self::expected_score(ratings[player], ratings[opponent])
+                    })
+                    .sum();
+                ratings[player] + k_factor * change
+            })
+            .collect()
+    }
+
+    /// Rates the matches in the order given and returns every player's rating after each of their matches.
+    pub fn rate_matches<'a>(&self, matches: impl IntoIterator<Item = &'a Match>) -> Vec<RatingHistory> {
+        let mut ratings: HashMap<String, f64> = HashMap::new();
+        let mut histories: HashMap<String, Vec<RatingPoint>> = HashMap::new();
+
+        for played in matches {
+            let current: Vec<f64> = played.participants.iter()
+                .map(|participant| *ratings.get(&participant.player_name).unwrap_or(&self.initial_rating))
+                .collect();
+            let updated = self.rate_game(&current, &played.placements());
+
+            for (participant, rating) in played.participants.iter().zip(updated) {
+                ratings.insert(participant.player_name.clone(), rating);
+                histories.entry(participant.player_name.clone())
+                    .or_default()
+                    .push(RatingPoint { match_id: played.id, rating });
+            }
+        }
+
+        let mut histories: Vec<RatingHistory> = histories.into_iter()
+            .map(|(player_name, history)| RatingHistory { player_name, history })
+            .collect();
+        histories.sort_by(|a, b| a.player_name.cmp(&b.player_name));
+        histories
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use crate::model::{kingdom::Kingdom, league::league_match::Participant};
+
+    fn league_match(id: u32, results: &[(&str, i16, u8)]) -> Match {
+        Match {
+            id,
+            season_id: 1,
+            kingdom: Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] },
+            participants: results.iter()
+                .map(|(player_name, victory_points, turns)| Participant {
+                    player_name: player_name.to_string(),
+                    victory_points: *victory_points,
+                    turns: *turns
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_expected_score_equal_ratings() {
+        assert_eq!(RatingEngine::expected_score(1500.0, 1500.0), 0.5);
+    }
+
+    #[test]
+    fn test_rate_game_two_players() {
+        let engine = RatingEngine::default();
+        assert_eq!(engine.rate_game(&[1500.0, 1500.0], &[1, 2]), vec![1516.0, 1484.0]);
+    }
+
+    #[test]
+    fn test_rate_game_shared_place_equal_ratings() {
+        let engine = RatingEngine::default();
+        assert_eq!(engine.rate_game(&[1500.0, 1500.0, 1500.0], &[1, 1, 3]), vec![1508.0, 1508.0, 1484.0]);
+    }
+
+    #[test]
+    fn test_rate_game_single_player() {
+        let engine = RatingEngine::default();
+        assert_eq!(engine.rate_game(&[1500.0], &[1]), vec![1500.0]);
+    }
+
+    #[test]
+    fn test_rate_matches() {
+        let engine = RatingEngine::default();
+        let matches = vec![
+            league_match(1, &[("A", 30, 15), ("B", 20, 15)]),
+            league_match(2, &[("B", 30, 15), ("C", 30, 16)]),
+        ];
+        let histories = engine.rate_matches(&matches);
+        let names: Vec<&str> = histories.iter().map(|history| history.player_name.as_str()).collect();
+        assert_eq!(names, vec!["A", "B", "C"]);
+        assert_eq!(histories[0].history, vec![RatingPoint { match_id: 1, rating: 1516.0 }]);
+        assert_eq!(histories[1].history.len(), 2);
+        assert_eq!(histories[1].history[0], RatingPoint { match_id: 1, rating: 1484.0 });
+        assert!(histories[1].current_rating().unwrap() > 1484.0);
+        assert!(histories[2].current_rating().unwrap() < DEFAULT_RATING);
+    }
+
+    proptest! {
+        #[test]
+        fn test_two_player_rating_is_conserved(
+            first in 100.0f64..3000.0,
+            second in 100.0f64..3000.0,
+            first_place in 1u8..=2,
+            second_place in 1u8..=2
+        ) {
+            let engine = RatingEngine::default();
+            let updated = engine.rate_game(&[first, second], &[first_place, second_place]);
+            prop_assert!((updated[0] + updated[1] - first - second).abs() < 1e-9);
+        }
+
+        #[test]
+        fn test_multiplayer_rating_is_conserved(
+            ratings in proptest::collection::vec(100.0f64..3000.0, 2..=6),
+            seed_placements in proptest::collection::vec(1u8..=6, 6)
+        ) {
+            let engine = RatingEngine::default();
+            let placements = &seed_placements[..ratings.len()];
+            let updated = engine.rate_game(&ratings, placements);
+            let before: f64 = ratings.iter().sum();
+            let after: f64 = updated.iter().sum();
+            prop_assert!((before - after).abs() < 1e-9);
+        }
+
+        #[test]
+        fn test_outright_winner_never_loses_rating(
+            ratings in proptest::collection::vec(100.0f64..3000.0, 2..=6)
+        ) {
+            let engine = RatingEngine::default();
+            let placements: Vec<u8> = (1..=ratings.len() as u8).collect();
+            let updated = engine.rate_game(&ratings, &placements);
+            prop_assert!(updated[0] >= ratings[0]);
+            prop_assert!(updated[ratings.len() - 1] <= ratings[ratings.len() - 1]);
+        }
+
+        #[test]
+        fn test_rating_change_is_bounded_by_k_factor(
+            ratings in proptest::collection::vec(100.0f64..3000.0, 2..=6),
+            seed_placements in proptest::collection::vec(1u8..=6, 6)
+        ) {
+            let engine = RatingEngine::default();
+            let updated = engine.rate_game(&ratings, &seed_placements[..ratings.len()]);
+            for (before, after) in ratings.iter().zip(updated.iter()) {
+                prop_assert!((after - before).abs() <= engine.k_factor);
+            }
+        }
+
+        #[test]
+        fn test_all_tied_equal_ratings_do_not_change(
+            rating in 100.0f64..3000.0,
+            players in 2usize..=6
+        ) {
+            let engine = RatingEngine::default();
+            let updated = engine.rate_game(&vec![rating; players], &vec![1; players]);
+            for after in updated {
+                prop_assert!((after - rating).abs() < 1e-9);
+            }
+        }
+    }
+}
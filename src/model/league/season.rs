@@ -0,0 +1,26 @@
+use rocket::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Season {
+    pub id: u16,
+    pub name: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+
+    #[test]
+    fn test_serialize() {
+        let season = Season { id: 1, name: "Spring".to_string() };
+        assert_eq!(serde_json::to_string(&season).unwrap(), "{\"id\":1,\"name\":\"Spring\"}");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let season: Season = serde_json::from_str("{\"id\":1,\"name\":\"Spring\"}").unwrap();
+        assert_eq!(season, Season { id: 1, name: "Spring".to_string() });
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use rocket::serde::{Deserialize, Serialize};
+
+use super::league_match::Match;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Standing {
+    pub player_name: String,
+    pub matches_played: u16,
+    pub wins: u16,
+    pub league_points: u32,
+    pub total_victory_points: i32,
+}
+
+/// Standings for every player in the given matches, best first.
+/// A player scores one league point for every opponent they finish ahead of.
+pub fn build_standings<'a>(matches: impl IntoIterator<Item = &'a Match>) -> Vec<Standing> {
+    let mut standings: HashMap<String, Standing> = HashMap::new();
+    for played in matches {
+        let placements = played.placements();
+        for (participant, place) in played.participants.iter().zip(placements.iter()) {
+            let standing = standings.entry(participant.player_name.clone()).or_insert_with(|| Standing {
+                player_name: participant.player_name.clone(),
+                matches_played: 0,
+                wins: 0,
+                league_points: 0,
+                total_victory_points: 0
+            });
+            standing.matches_played += 1;
+            if *place == 1 {
+                standing.wins += 1;
+            }
+            standing.league_points += placements.iter().filter(|other| *other > place).count() as u32;
+            standing.total_victory_points += participant.victory_points as i32;
+        }
+    }
+
+    let mut standings: Vec<Standing> = standings.into_values().collect();
+    standings.sort_by(|a, b| {
+        b.league_points.cmp(&a.league_points)
+            .then(b.wins.cmp(&a.wins))
+            .then(a.player_name.cmp(&b.player_name))
+    });
+    standings
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rocket::serde::json::serde_json;
+    use crate::model::{kingdom::Kingdom, league::league_match::Participant};
+
+    fn league_match(id: u32, results: &[(&str, i16, u8)]) -> Match {
+        Match {
+            id,
+            season_id: 1,
+            kingdom: Kingdom { supply_card_ids: vec![101], basic_card_ids: vec![0], landscape_ids: vec![] },
+            participants: results.iter()
+                .map(|(player_name, victory_points, turns)| Participant {
+                    player_name: player_name.to_string(),
+                    victory_points: *victory_points,
+                    turns: *turns
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_build_standings() {
+        let matches = vec![
+            league_match(1, &[("A", 30, 15), ("B", 20, 15), ("C", 10, 15)]),
+            league_match(2, &[("B", 30, 15), ("C", 20, 15)]),
+        ];
+        let standings = build_standings(&matches);
+        assert_eq!(standings, vec![
+            Standing { player_name: "A".to_string(), matches_played: 1, wins: 1, league_points: 2, total_victory_points: 30 },
+            Standing { player_name: "B".to_string(), matches_played: 2, wins: 1, league_points: 2, total_victory_points: 50 },
+            Standing { player_name: "C".to_string(), matches_played: 2, wins: 0, league_points: 0, total_victory_points: 30 },
+        ]);
+    }
+
+    #[test]
+    fn test_build_standings_shared_win() {
+        let matches = vec![league_match(1, &[("A", 30, 15), ("B", 30, 15)])];
+        let standings = build_standings(&matches);
+        assert_eq!(standings[0].wins, 1);
+        assert_eq!(standings[1].wins, 1);
+        assert_eq!(standings[0].league_points, 0);
+    }
+
+    #[test]
+    fn test_build_standings_empty() {
+        assert!(build_standings(&Vec::<Match>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let standing = Standing { player_name: "A".to_string(), matches_played: 1, wins: 1, league_points: 2, total_victory_points: 30 };
+        assert_eq!(
+            serde_json::to_string(&standing).unwrap(),
+            "{\"player_name\":\"A\",\"matches_played\":1,\"wins\":1,\"league_points\":2,\"total_victory_points\":30}");
+    }
+}
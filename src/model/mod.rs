@@ -1,8 +1,20 @@
 pub mod card;
 pub mod card_type;
 pub mod card_tag;
+pub mod cost;
+pub mod card_search;
+pub mod card_query;
+pub mod card_relationships;
+pub mod card_text;
+pub mod card_text_search;
+pub mod card_validation;
+pub mod owned_editions;
 pub mod set;
 pub mod data_loaders;
 pub mod state;
 pub mod scrapers;
 pub mod kingdom;
+pub mod kingdom_generator;
+pub mod kingdom_score;
+pub mod landscape;
+pub mod league;
@@ -0,0 +1,61 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::card_validation::BASIC_CARD_SET_ID;
+
+/// Which editions of which sets a group owns.
+/// Nothing owned means every edition of every set can be used, the basic cards are always owned.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct OwnedEditions {
+    set_editions: BTreeMap<u8, BTreeSet<u8>>,
+}
+
+impl OwnedEditions {
+    pub fn is_empty(&self) -> bool {
+        self.set_editions.is_empty()
+    }
+
+    pub fn add(&mut self, set_id: u8, edition: u8) {
+        self.set_editions.entry(set_id).or_default().insert(edition);
+    }
+
+    /// Whether a card or landscape printed in `editions` of `set_id` is in one of the owned editions.
+    pub fn owns(&self, set_id: u8, editions: &[u8]) -> bool {
+        if self.is_empty() || set_id == BASIC_CARD_SET_ID {
+            return true;
+        }
+        self.set_editions.get(&set_id)
+            .is_some_and(|owned| editions.iter().any(|edition| owned.contains(edition)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nothing_owned_allows_everything() {
+        let owned_editions = OwnedEditions::default();
+        assert!(owned_editions.owns(1, &[1]));
+        assert!(owned_editions.owns(12, &[1]));
+    }
+
+    #[test]
+    fn test_owns() {
+        let mut owned_editions = OwnedEditions::default();
+        owned_editions.add(1, 2);
+        owned_editions.add(2, 1);
+        owned_editions.add(2, 2);
+        assert!(owned_editions.owns(1, &[2]));
+        assert!(owned_editions.owns(1, &[1, 2]));
+        assert!(!owned_editions.owns(1, &[1]));
+        assert!(owned_editions.owns(2, &[1]));
+        assert!(!owned_editions.owns(3, &[1, 2]));
+    }
+
+    #[test]
+    fn test_basic_cards_always_owned() {
+        let mut owned_editions = OwnedEditions::default();
+        owned_editions.add(1, 2);
+        assert!(owned_editions.owns(BASIC_CARD_SET_ID, &[1]));
+    }
+}
@@ -0,0 +1,191 @@
+use std::{error::Error, fmt, io::Cursor};
+
+use image::{DynamicImage, ImageFormat, codecs::jpeg::JpegEncoder, imageops::FilterType};
+
+use super::media_type::{MediaType, MediaTypeError, detect_media_type};
+
+const JPEG_QUALITY: u8 = 90;
+
+/// The largest a scraped image is saved, bigger images are shrunk to fit keeping their shape.
+/// The default leaves room for full size card art, which is about 1250 by 2000.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ImageBounds {
+    pub max_width: u32,
+    pub max_height: u32,
+}
+
+impl Default for ImageBounds {
+    fn default() -> Self {
+        Self { max_width: 1280, max_height: 2048 }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NormalisedFile {
+    pub bytes: Vec<u8>,
+    pub media_type: MediaType,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NormaliseError {
+    MediaType(MediaTypeError),
+    Mismatch { expected: MediaType, found: MediaType },
+    Image(String),
+}
+
+impl fmt::Display for NormaliseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormaliseError::MediaType(error) => write!(f, "{}", error),
+            NormaliseError::Mismatch { expected, found } => {
+                write!(f, "Expected {} but downloaded {}.", expected.mime_type(), found.mime_type())
+            },
+            NormaliseError::Image(error) => write!(f, "Could not convert the image {}", error),
+        }
+    }
+}
+
+impl Error for NormaliseError {}
+
+impl From<MediaTypeError> for NormaliseError {
+    fn from(error: MediaTypeError) -> Self {
+        NormaliseError::MediaType(error)
+    }
+}
+
+fn image_format(media_type: MediaType) -> Option<ImageFormat> {
+    match media_type {
+        MediaType::Jpeg => Some(ImageFormat::Jpeg),
+        MediaType::Png => Some(ImageFormat::Png),
+        MediaType::Gif => Some(ImageFormat::Gif),
+        MediaType::Webp => Some(ImageFormat::WebP),
+        MediaType::Svg | MediaType::Pdf => None,
+    }
+}
+
+fn encode_image(image: &DynamicImage, target: MediaType) -> Result<Vec<u8>, NormaliseError> {
+    let mut bytes = vec![];
+    let result = match target {
+        // jpeg has no transparency
+        MediaType::Jpeg => JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY).encode_image(&DynamicImage::ImageRgb8(image.to_rgb8())),
+        _ => {
+            let format = image_format(target).ok_or(NormaliseError::Image(format!("to {}", target.mime_type())))?;
+            image.write_to(&mut Cursor::new(&mut bytes), format)
+        },
+    };
+    result.map_err(|error| NormaliseError::Image(error.to_string()))?;
+    Ok(bytes)
+}
+
+/// Checks a download is what it will be saved as and converts raster images to `target` within `bounds`.
+/// Images already in the right format and size are kept byte for byte. SVGs cannot be rasterised so one
+/// downloaded for a raster file is a mismatch rather than an svg under a `.jpeg` name.
+pub fn normalise_download(
+    bytes: &[u8],
+    content_type: Option<&str>,
+    target: Option<MediaType>,
+    bounds: ImageBounds
+) -> Result<NormalisedFile, NormaliseError> {
+    let found = detect_media_type(bytes, content_type)?;
+    let keep = || Ok(NormalisedFile { bytes: bytes.to_vec(), media_type: found });
+    let target = match target {
+        Some(target) => target,
+        None => return keep(),
+    };
+
+    if !(target.is_raster() && found.is_raster()) {
+        return if target == found { keep() } else { Err(NormaliseError::Mismatch { expected: target, found }) };
+    }
+
+    let format = image_format(found).ok_or(NormaliseError::Image(format!("from {}", found.mime_type())))?;
+    let image = image::load_from_memory_with_format(bytes, format).map_err(|error| NormaliseError::Image(error.to_string()))?;
+    let fits = image.width() <= bounds.max_width && image.height() <= bounds.max_height;
+    if fits && found == target {
+        return keep();
+    }
+    let image = match fits {
+        true => image,
+        false => image.resize(bounds.max_width, bounds.max_height, FilterType::Lanczos3),
+    };
+    Ok(NormalisedFile { bytes: encode_image(&image, target)?, media_type: target })
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::read;
+
+    use image::{ImageBuffer, Rgba};
+
+    use super::*;
+
+    fn png_bytes(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(width, height, Rgba([200, 100, 50, 128])));
+        encode_image(&image, MediaType::Png).unwrap()
+    }
+
+    #[test]
+    fn test_normalise_keeps_matching_image() {
+        let copper = read("src/model/scraped_data/cards/Copper_1.jpeg").unwrap();
+        let normalised = normalise_download(&copper, Some("image/jpeg"), Some(MediaType::Jpeg), ImageBounds::default()).unwrap();
+        assert_eq!(normalised, NormalisedFile { bytes: copper, media_type: MediaType::Jpeg });
+    }
+
+    #[test]
+    fn test_normalise_converts_format() {
+        let normalised = normalise_download(&png_bytes(40, 20), None, Some(MediaType::Jpeg), ImageBounds::default()).unwrap();
+        assert_eq!(normalised.media_type, MediaType::Jpeg);
+        assert_eq!(MediaType::sniff(&normalised.bytes), Some(MediaType::Jpeg));
+        let image = image::load_from_memory(&normalised.bytes).unwrap();
+        assert_eq!((image.width(), image.height()), (40, 20));
+    }
+
+    #[test]
+    fn test_normalise_shrinks_large_image() {
+        let bounds = ImageBounds { max_width: 100, max_height: 100 };
+        let normalised = normalise_download(&png_bytes(400, 200), Some("image/png"), Some(MediaType::Png), bounds).unwrap();
+        assert_eq!(normalised.media_type, MediaType::Png);
+        let image = image::load_from_memory(&normalised.bytes).unwrap();
+        assert_eq!((image.width(), image.height()), (100, 50));
+    }
+
+    #[test]
+    fn test_normalise_svg() {
+        let svg = read("src/model/scrapers/sample_files/github.svg").unwrap();
+        assert_eq!(normalise_download(&svg, Some("image/svg+xml"), Some(MediaType::Jpeg), ImageBounds::default()),
+            Err(NormaliseError::Mismatch { expected: MediaType::Jpeg, found: MediaType::Svg }));
+        let normalised = normalise_download(&svg, Some("image/svg+xml"), Some(MediaType::Svg), ImageBounds::default()).unwrap();
+        assert_eq!(normalised, NormalisedFile { bytes: svg, media_type: MediaType::Svg });
+    }
+
+    #[test]
+    fn test_normalise_without_target() {
+        let normalised = normalise_download(b"%PDF-1.7", None, None, ImageBounds::default()).unwrap();
+        assert_eq!(normalised.media_type, MediaType::Pdf);
+    }
+
+    #[test]
+    fn test_normalise_mismatch() {
+        assert_eq!(
+            normalise_download(b"%PDF-1.7", None, Some(MediaType::Png), ImageBounds::default()),
+            Err(NormaliseError::Mismatch { expected: MediaType::Png, found: MediaType::Pdf }));
+        assert_eq!(
+            normalise_download(&png_bytes(1, 1), None, Some(MediaType::Pdf), ImageBounds::default()),
+            Err(NormaliseError::Mismatch { expected: MediaType::Pdf, found: MediaType::Png }));
+        assert_eq!(
+            NormaliseError::Mismatch { expected: MediaType::Pdf, found: MediaType::Png }.to_string(),
+            "Expected application/pdf but downloaded image/png.");
+    }
+
+    #[test]
+    fn test_normalise_rejects_html() {
+        assert_eq!(
+            normalise_download(b"<!DOCTYPE html><title>404</title>", Some("text/html"), Some(MediaType::Jpeg), ImageBounds::default()),
+            Err(NormaliseError::MediaType(MediaTypeError::Html)));
+    }
+
+    #[test]
+    fn test_normalise_broken_image() {
+        let result = normalise_download(b"\x89PNG\r\n\x1a\nbroken", None, Some(MediaType::Jpeg), ImageBounds::default());
+        assert!(matches!(result, Err(NormaliseError::Image(_))));
+    }
+}
@@ -0,0 +1,187 @@
+use std::{error::Error, fmt, path::Path};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MediaType {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+    Svg,
+    Pdf,
+}
+
+impl MediaType {
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            MediaType::Jpeg => "image/jpeg",
+            MediaType::Png => "image/png",
+            MediaType::Gif => "image/gif",
+            MediaType::Webp => "image/webp",
+            MediaType::Svg => "image/svg+xml",
+            MediaType::Pdf => "application/pdf",
+        }
+    }
+
+    /// Ignores parameters such as `; charset=utf-8`.
+    pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+        let essence = mime_type.split(';').next().unwrap_or("").trim().to_lowercase();
+        match essence.as_str() {
+            "image/jpeg" | "image/jpg" => Some(MediaType::Jpeg),
+            "image/png" => Some(MediaType::Png),
+            "image/gif" => Some(MediaType::Gif),
+            "image/webp" => Some(MediaType::Webp),
+            "image/svg+xml" => Some(MediaType::Svg),
+            "application/pdf" => Some(MediaType::Pdf),
+            _ => None,
+        }
+    }
+
+    /// The type a scraped file is saved as, taken from the extension of the path it is saved to.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_string_lossy().to_lowercase();
+        match extension.as_str() {
+            "jpeg" | "jpg" => Some(MediaType::Jpeg),
+            "png" => Some(MediaType::Png),
+            "gif" => Some(MediaType::Gif),
+            "webp" => Some(MediaType::Webp),
+            "svg" => Some(MediaType::Svg),
+            "pdf" => Some(MediaType::Pdf),
+            _ => None,
+        }
+    }
+
+    /// Recognises a file from its first bytes.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+            return Some(MediaType::Jpeg);
+        }
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            return Some(MediaType::Png);
+        }
+        if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            return Some(MediaType::Gif);
+        }
+        if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+            return Some(MediaType::Webp);
+        }
+        if bytes.starts_with(b"%PDF-") {
+            return Some(MediaType::Pdf);
+        }
+        let start = text_start(bytes);
+        if start.starts_with("<svg") || (start.starts_with("<?xml") && start.contains("<svg")) {
+            return Some(MediaType::Svg);
+        }
+        None
+    }
+
+    /// Raster images can be decoded, resized and saved in another raster format.
+    pub fn is_raster(&self) -> bool {
+        matches!(self, MediaType::Jpeg | MediaType::Png | MediaType::Gif | MediaType::Webp)
+    }
+}
+
+/// The lower case start of a text file without leading white space or a byte order mark.
+fn text_start(bytes: &[u8]) -> String {
+    let start = &bytes[..bytes.len().min(512)];
+    let start = start.strip_prefix(b"\xef\xbb\xbf").unwrap_or(start);
+    String::from_utf8_lossy(start).trim_start().to_lowercase()
+}
+
+fn is_html(bytes: &[u8], content_type: Option<&str>) -> bool {
+    let start = text_start(bytes);
+    content_type.is_some_and(|content_type| content_type.trim().to_lowercase().starts_with("text/html"))
+        || start.starts_with("<!doctype html")
+        || start.starts_with("<html")
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MediaTypeError {
+    Html,
+    Unknown { content_type: Option<String> },
+}
+
+impl fmt::Display for MediaTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MediaTypeError::Html => write!(f, "Downloaded an HTML page instead of a file."),
+            MediaTypeError::Unknown { content_type: Some(content_type) } => write!(f, "Downloaded an unknown file type {}.", content_type),
+            MediaTypeError::Unknown { content_type: None } => write!(f, "Downloaded an unknown file type."),
+        }
+    }
+}
+
+impl Error for MediaTypeError {}
+
+/// What a download really is, the bytes are trusted over the Content-Type header.
+/// HTML is refused, it is what wikis and CDNs send back for a missing file or an error.
+pub fn detect_media_type(bytes: &[u8], content_type: Option<&str>) -> Result<MediaType, MediaTypeError> {
+    if let Some(media_type) = MediaType::sniff(bytes) {
+        return Ok(media_type);
+    }
+    if is_html(bytes, content_type) {
+        return Err(MediaTypeError::Html);
+    }
+    content_type.and_then(MediaType::from_mime_type)
+        .ok_or_else(|| MediaTypeError::Unknown { content_type: content_type.map(str::to_string) })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs::read, path::Path};
+
+    use super::*;
+
+    #[test]
+    fn test_sniff() {
+        assert_eq!(MediaType::sniff(&read("src/model/scraped_data/cards/Copper_1.jpeg").unwrap()), Some(MediaType::Jpeg));
+        assert_eq!(MediaType::sniff(&read("src/model/scraped_data/Dominion_1/icon.png").unwrap()), Some(MediaType::Png));
+        assert_eq!(MediaType::sniff(&read("src/model/scrapers/sample_files/github.svg").unwrap()), Some(MediaType::Svg));
+        assert_eq!(MediaType::sniff(b"GIF89a...."), Some(MediaType::Gif));
+        assert_eq!(MediaType::sniff(b"RIFF\x00\x00\x00\x00WEBPVP8 "), Some(MediaType::Webp));
+        assert_eq!(MediaType::sniff(b"%PDF-1.7"), Some(MediaType::Pdf));
+        assert_eq!(MediaType::sniff(b"\xef\xbb\xbf <?xml version=\"1.0\"?>\n<svg></svg>"), Some(MediaType::Svg));
+        assert_eq!(MediaType::sniff(b"<?xml version=\"1.0\"?><feed></feed>"), None);
+        assert_eq!(MediaType::sniff(b""), None);
+    }
+
+    #[test]
+    fn test_from_mime_type() {
+        assert_eq!(MediaType::from_mime_type("image/png"), Some(MediaType::Png));
+        assert_eq!(MediaType::from_mime_type("Image/JPEG; charset=binary"), Some(MediaType::Jpeg));
+        assert_eq!(MediaType::from_mime_type("text/html"), None);
+        for media_type in [MediaType::Jpeg, MediaType::Png, MediaType::Gif, MediaType::Webp, MediaType::Svg, MediaType::Pdf] {
+            assert_eq!(MediaType::from_mime_type(media_type.mime_type()), Some(media_type));
+        }
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(MediaType::from_path(Path::new("cards/Copper_1.jpeg")), Some(MediaType::Jpeg));
+        assert_eq!(MediaType::from_path(Path::new("Dominion_1/rules.PDF")), Some(MediaType::Pdf));
+        assert_eq!(MediaType::from_path(Path::new("manifest.json")), None);
+        assert_eq!(MediaType::from_path(Path::new("no_extension")), None);
+    }
+
+    #[test]
+    fn test_detect_media_type_trusts_bytes() {
+        assert_eq!(detect_media_type(b"%PDF-1.7", Some("text/html")), Ok(MediaType::Pdf));
+        assert_eq!(detect_media_type(b"\x89PNG\r\n\x1a\n", Some("image/jpeg")), Ok(MediaType::Png));
+    }
+
+    #[test]
+    fn test_detect_media_type_falls_back_to_header() {
+        assert_eq!(detect_media_type(b"not sniffable", Some("image/webp")), Ok(MediaType::Webp));
+        assert_eq!(
+            detect_media_type(b"not sniffable", Some("application/zip")),
+            Err(MediaTypeError::Unknown { content_type: Some("application/zip".to_string()) }));
+        assert_eq!(detect_media_type(b"not sniffable", None), Err(MediaTypeError::Unknown { content_type: None }));
+    }
+
+    #[test]
+    fn test_detect_media_type_rejects_html() {
+        assert_eq!(detect_media_type(b"\n  <!DOCTYPE html><html></html>", None), Err(MediaTypeError::Html));
+        assert_eq!(detect_media_type(b"<html><body>Not Found</body></html>", Some("image/jpeg")), Err(MediaTypeError::Html));
+        assert_eq!(detect_media_type(b"File not found", Some("text/html; charset=UTF-8")), Err(MediaTypeError::Html));
+        assert_eq!(MediaTypeError::Html.to_string(), "Downloaded an HTML page instead of a file.");
+    }
+}
@@ -1,3 +1,11 @@
 pub mod scraper_helpers;
 pub mod scrape_set_files;
 pub mod scrape_card_files;
+pub mod scrape_engine;
+pub mod scraper_cli;
+pub mod media_type;
+pub mod image_normaliser;
+pub mod wiki_dump;
+pub mod wiki_cards;
+#[cfg(test)]
+pub mod stub_server;
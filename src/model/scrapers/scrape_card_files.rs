@@ -1,16 +1,26 @@
+use std::path::Path;
+
 use crate::model::{card::{Card, CardFilepath}};
 
-use super::scraper_helpers::download_file_to_path;
+use super::{image_normaliser::ImageBounds, scrape_engine::DownloadJob, scraper_helpers::{Downloader, download_file_to_path}};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
 
+/// The art of every edition of the card, saved to `cards` in the output directory.
+pub(crate) fn card_download_jobs(card: &Card, output_dir: &Path) -> Vec<DownloadJob> {
+    card.editions.iter().zip(card.art_url.iter())
+        .map(|(edition, url)| DownloadJob {
+            url: url.clone(),
+            path: output_dir.join("cards").join(format!("{}.jpeg", card.get_art_file_name(*edition)))
+        })
+        .collect()
+}
+
 #[allow(dead_code)]
-pub(crate) async fn scrape_card_files(card: &Card) -> Result<()> {
-    for (index, edition) in card.editions.iter().enumerate() {
-        download_file_to_path(
-            &card.get_art_path(*edition),
-            card.art_url.get(index).unwrap()).await?;
+pub(crate) async fn scrape_card_files(downloader: &dyn Downloader, card: &Card, output_dir: &Path) -> Result<()> {
+    for job in card_download_jobs(card, output_dir) {
+        download_file_to_path(downloader, &job.path.to_string_lossy(), &job.url, ImageBounds::default()).await?;
     }
     Ok(())
 } 
@@ -18,24 +28,21 @@ pub(crate) async fn scrape_card_files(card: &Card) -> Result<()> {
 
 #[cfg(test)]
 mod test {
-    use std::fs::remove_file;
+    use std::{env::temp_dir, fs::{read, remove_dir_all}};
 
     use tokio;
-    use file_diff::diff;
-    use crate::model::{card_tag::CardTag, card_type::CardType, card::{Card, CardFilepath}};
+    use crate::model::{card_tag::CardTag, card_type::CardType, card::{Card, CardFilepath}, card_relationships::CardRelationships, cost::Cost};
+    use crate::model::scrapers::{media_type::MediaType, scraper_helpers::HttpDownloader, stub_server::{StubResponse, StubServer}};
     use super::scrape_card_files;
 
-    #[tokio::test]
-    async fn test_scrape_card_files() {
-        let _ = remove_file("src\\model\\scraped_data\\cards\\test-name_13.jpeg");
-        let _ = remove_file("src\\model\\scraped_data\\cards\\test-name_21.jpeg");
-        let logo_url = "https://github.githubassets.com/favicons/favicon.svg";
-        let card = Card { 
+    fn test_card(art_url: Vec<String>) -> Card {
+        Card { 
             id: 1,
             name: "test name".to_string(),
             supply_card: true,
             basic_card: false,
             card_counts: [10, 10, 10, 10, 10],  
+            cost: Cost::from_coins(4),
             set_id: 0,
             editions: [13, 21].to_vec(),
             card_tags: [CardTag::Costs4].to_vec(),
@@ -43,23 +50,40 @@ mod test {
             kingdom_synergies: [[CardTag::CanReplaceAction].to_vec()].to_vec(),
             kingdom_anti_synergies: [[CardTag::WillReplaceAction].to_vec()].to_vec(),
             card_types: [CardType::Action, CardType::Attack ,CardType::Curse].to_vec(),
-            art_url: [logo_url.to_string(), logo_url.to_string()].to_vec()
-        };
-        let files = [
-            card.get_art_path(13),
-            card.get_art_path(21),
-        ];
-        let _ = scrape_card_files(&card).await;
-
-        let expected_sample_file_path = "src/model/scrapers/sample_files/github.svg";
-
-        for file in files {
-            println!("{}", file);
-            assert!(diff(expected_sample_file_path, &file));
+            art_url,
+            relationships: CardRelationships::default(),
+            texts: vec![]
         }
-        let _ = remove_file("src\\model\\scraped_data\\cards\\test-name_13.jpeg");
-        let _ = remove_file("src\\model\\scraped_data\\cards\\test-name_21.jpeg");
+    }
+
+    #[tokio::test]
+    async fn test_scrape_card_files() {
+        let png = read("src/model/scraped_data/Dominion_1/icon.png").unwrap();
+        let jpeg = read("src/model/scraped_data/cards/Copper_1.jpeg").unwrap();
+        let server = StubServer::start(vec![
+            ("/test_name_13.jpg", vec![StubResponse::with_content_type("image/png", &png)]),
+            ("/test_name_21.jpg", vec![StubResponse::with_content_type("image/jpeg", &jpeg)]),
+        ]).await;
+        let output_dir = temp_dir().join(format!("dominion_scrape_card_files_{}", std::process::id()));
+        let _ = remove_dir_all(&output_dir);
+        let card = test_card(vec![server.url("/test_name_13.jpg"), server.url("/test_name_21.jpg")]);
+        scrape_card_files(&HttpDownloader::default(), &card, &output_dir).await.unwrap();
 
+        let converted = read(output_dir.join("cards").join(format!("{}.jpeg", card.get_art_file_name(13)))).unwrap();
+        assert_eq!(MediaType::sniff(&converted), Some(MediaType::Jpeg));
+        assert_eq!(read(output_dir.join("cards/test-name_21.jpeg")).unwrap(), jpeg);
+        let _ = remove_dir_all(output_dir);
     }
 
+    #[tokio::test]
+    async fn test_scrape_card_files_stops_on_error() {
+        let server = StubServer::start(vec![]).await;
+        let output_dir = temp_dir().join(format!("dominion_scrape_card_files_missing_{}", std::process::id()));
+        let card = test_card(vec![server.url("/missing.jpg"), server.url("/missing.jpg")]);
+
+        assert!(scrape_card_files(&HttpDownloader::default(), &card, &output_dir).await.is_err());
+        assert!(!output_dir.join("cards/test-name_13.jpeg").exists());
+        assert_eq!(server.request_count("/missing.jpg"), 1);
+        let _ = remove_dir_all(output_dir);
+    }
 }
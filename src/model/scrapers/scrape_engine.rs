@@ -0,0 +1,571 @@
+use std::{collections::{BTreeMap, BTreeSet}, fmt, fs, io::{self, ErrorKind}, path::{Path, PathBuf}, sync::Arc, time::Duration};
+
+use rocket::serde::{Deserialize, Serialize, json::serde_json};
+use sha2::{Digest, Sha256};
+use tokio::{sync::Semaphore, task::JoinSet, time::sleep};
+
+use super::{
+    image_normaliser::ImageBounds,
+    media_type::MediaType,
+    scraper_helpers::{Downloader, HttpDownloader, download_file_to_path},
+    wiki_cards::DRAFT_FOLDER_NAME
+};
+
+pub const DEFAULT_OUTPUT_DIR: &str = "src/model/scraped_data";
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+pub const REPORT_FILE_NAME: &str = "scrape_report.json";
+
+/// One file to fetch, `path` is where it is saved.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DownloadJob {
+    pub url: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScrapeOptions {
+    pub concurrency: usize,
+    pub max_attempts: u32,
+    // doubled after every failed attempt
+    pub initial_backoff: Duration,
+    pub image_bounds: ImageBounds,
+    pub manifest_path: PathBuf,
+    pub report_path: PathBuf,
+}
+
+impl ScrapeOptions {
+    /// The manifest and report are kept beside the files they describe.
+    pub fn for_output_dir(output_dir: &Path) -> Self {
+        Self {
+            concurrency: 4,
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            image_bounds: ImageBounds::default(),
+            manifest_path: output_dir.join(MANIFEST_FILE_NAME),
+            report_path: output_dir.join(REPORT_FILE_NAME),
+        }
+    }
+}
+
+impl Default for ScrapeOptions {
+    fn default() -> Self {
+        Self::for_output_dir(Path::new(DEFAULT_OUTPUT_DIR))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ManifestEntry {
+    pub url: String,
+    pub sha256: String,
+    // what the file really is, manifests written before this was recorded have none
+    #[serde(default)]
+    pub mime_type: Option<String>,
+}
+
+/// The checksum of every file downloaded so far, keyed by the file path relative to the manifest.
+/// A file is only fetched again when it is missing, changed on disk or its url changed.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Manifest {
+    pub files: BTreeMap<String, ManifestEntry>,
+}
+
+impl Manifest {
+    /// A missing manifest is an empty one, so the first run downloads everything.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|error| io::Error::new(ErrorKind::InvalidData, error)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write_json(path, self)
+    }
+
+    /// The MIME type recorded when `path` was downloaded.
+    pub fn mime_type(&self, manifest_path: &Path, path: &Path) -> Option<&str> {
+        self.files.get(&manifest_key(manifest_path, path))?.mime_type.as_deref()
+    }
+
+    fn is_current(&self, key: &str, job: &DownloadJob) -> bool {
+        match self.files.get(key) {
+            Some(entry) if entry.url == job.url => file_checksum(&job.path).is_ok_and(|sha256| sha256 == entry.sha256),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ScrapeFailure {
+    pub url: String,
+    pub path: String,
+    pub attempts: u32,
+    pub error: String,
+}
+
+/// What a scrape did, written to the report file with every failure so a run can be checked afterwards.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ScrapeReport {
+    pub downloaded: usize,
+    pub skipped: usize,
+    pub failures: Vec<ScrapeFailure>,
+}
+
+impl ScrapeReport {
+    pub fn total(&self) -> usize {
+        self.downloaded + self.skipped + self.failures.len()
+    }
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    if let Some(parent) = path.parent() { fs::create_dir_all(parent)? }
+    let json = serde_json::to_string_pretty(value).map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+    fs::write(path, json)
+}
+
+/// The lower case hex SHA-256 of a file.
+pub fn file_checksum(path: &Path) -> io::Result<String> {
+    let digest = Sha256::digest(fs::read(path)?);
+    Ok(digest.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+fn manifest_key(manifest_path: &Path, path: &Path) -> String {
+    let root = manifest_path.parent().unwrap_or(Path::new(""));
+    path.strip_prefix(root)
+        .or_else(|_| path.strip_prefix(fs::canonicalize(root).unwrap_or_default()))
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VerifyProblemKind {
+    Missing,
+    NotInManifest,
+    ChecksumMismatch,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VerifyProblem {
+    pub path: PathBuf,
+    pub kind: VerifyProblemKind,
+}
+
+impl fmt::Display for VerifyProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            VerifyProblemKind::Missing => write!(f, "{} is missing.", self.path.display()),
+            VerifyProblemKind::NotInManifest => write!(f, "{} is not in the manifest.", self.path.display()),
+            VerifyProblemKind::ChecksumMismatch => write!(f, "{} does not match its manifest checksum.", self.path.display()),
+        }
+    }
+}
+
+/// The jobs a scrape would download, the rest are already in the manifest.
+pub fn pending_jobs(jobs: Vec<DownloadJob>, manifest_path: &Path) -> io::Result<Vec<DownloadJob>> {
+    let manifest = Manifest::load(manifest_path)?;
+    Ok(jobs.into_iter()
+        .filter(|job| !manifest.is_current(&manifest_key(manifest_path, &job.path), job))
+        .collect())
+}
+
+/// Checks every job's file is on disk and matches the checksum recorded when it was downloaded.
+pub fn verify_files(jobs: &[DownloadJob], manifest_path: &Path) -> io::Result<Vec<VerifyProblem>> {
+    let manifest = Manifest::load(manifest_path)?;
+    let mut problems = vec![];
+    for job in jobs {
+        let kind = match (job.path.exists(), manifest.files.get(&manifest_key(manifest_path, &job.path))) {
+            (false, _) => VerifyProblemKind::Missing,
+            (true, None) => VerifyProblemKind::NotInManifest,
+            (true, Some(entry)) if file_checksum(&job.path)? != entry.sha256 => VerifyProblemKind::ChecksumMismatch,
+            (true, Some(_)) => continue,
+        };
+        problems.push(VerifyProblem { path: job.path.clone(), kind });
+    }
+    Ok(problems)
+}
+
+fn files_under(folder: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    if !folder.exists() {
+        return Ok(files);
+    }
+    for entry in fs::read_dir(folder)? {
+        let path = entry?.path();
+        match path.is_dir() {
+            true => files.extend(files_under(&path)?),
+            false => files.push(path),
+        }
+    }
+    Ok(files)
+}
+
+/// Deletes the files in the output directory no job downloads and drops them from the manifest.
+/// The manifest, report and any wiki drafts are kept, with `dry_run` nothing is changed and the files are only listed.
+pub fn prune_files(jobs: &[DownloadJob], options: &ScrapeOptions, dry_run: bool) -> io::Result<Vec<PathBuf>> {
+    let manifest_path = &options.manifest_path;
+    let output_dir = manifest_path.parent().unwrap_or(Path::new(""));
+    let kept: BTreeSet<String> = jobs.iter()
+        .map(|job| job.path.as_path())
+        .chain([manifest_path.as_path(), options.report_path.as_path()])
+        .map(|path| manifest_key(manifest_path, path))
+        .collect();
+
+    let draft_dir = output_dir.join(DRAFT_FOLDER_NAME);
+    let mut pruned = vec![];
+    for path in files_under(output_dir)? {
+        if !kept.contains(&manifest_key(manifest_path, &path)) && !path.starts_with(&draft_dir) {
+            if !dry_run { fs::remove_file(&path)? }
+            pruned.push(path);
+        }
+    }
+    pruned.sort();
+
+    let mut manifest = Manifest::load(manifest_path)?;
+    let manifest_files = manifest.files.len();
+    manifest.files.retain(|key, _| kept.contains(key));
+    if !dry_run && manifest.files.len() != manifest_files {
+        manifest.save(manifest_path)?;
+    }
+    Ok(pruned)
+}
+
+/// Tries a download up to `max_attempts` times, waiting longer after each failure.
+async fn download_with_retries(
+    downloader: &dyn Downloader,
+    job: &DownloadJob,
+    max_attempts: u32,
+    initial_backoff: Duration,
+    image_bounds: ImageBounds
+) -> Result<MediaType, (u32, String)> {
+    let path = job.path.to_string_lossy();
+    let mut backoff = initial_backoff;
+    let mut attempt = 1;
+    loop {
+        match download_file_to_path(downloader, &path, &job.url, image_bounds).await {
+            Ok(media_type) => return Ok(media_type),
+            Err(error) if attempt >= max_attempts => return Err((attempt, error.to_string())),
+            Err(_) => {
+                sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            },
+        }
+    }
+}
+
+/// Downloads every job that is not already in the manifest over HTTP.
+pub async fn run_scrape(jobs: Vec<DownloadJob>, options: &ScrapeOptions) -> io::Result<ScrapeReport> {
+    run_scrape_with(jobs, options, Arc::new(HttpDownloader::default())).await
+}
+
+/// Downloads every job that is not already in the manifest, at most `concurrency` at a time.
+/// The manifest is saved after every download so a stopped scrape picks up where it left off.
+pub async fn run_scrape_with(jobs: Vec<DownloadJob>, options: &ScrapeOptions, downloader: Arc<dyn Downloader>) -> io::Result<ScrapeReport> {
+    let mut manifest = Manifest::load(&options.manifest_path)?;
+    let mut report = ScrapeReport::default();
+    let semaphore = Arc::new(Semaphore::new(options.concurrency.max(1)));
+    let mut downloads = JoinSet::new();
+
+    for job in jobs {
+        let key = manifest_key(&options.manifest_path, &job.path);
+        if manifest.is_current(&key, &job) {
+            report.skipped += 1;
+            continue;
+        }
+        let (semaphore, downloader) = (semaphore.clone(), downloader.clone());
+        let (max_attempts, initial_backoff, image_bounds) = (options.max_attempts.max(1), options.initial_backoff, options.image_bounds);
+        downloads.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("the scrape semaphore is never closed");
+            let result = download_with_retries(downloader.as_ref(), &job, max_attempts, initial_backoff, image_bounds).await;
+            (key, job, result)
+        });
+    }
+
+    while let Some(joined) = downloads.join_next().await {
+        let (key, job, result) = joined.map_err(io::Error::other)?;
+        let checksum = match result {
+            Ok(media_type) => file_checksum(&job.path)
+                .map(|sha256| (sha256, media_type))
+                .map_err(|error| (1, error.to_string())),
+            Err(failure) => Err(failure),
+        };
+        match checksum {
+            Ok((sha256, media_type)) => {
+                let mime_type = Some(media_type.mime_type().to_string());
+                manifest.files.insert(key, ManifestEntry { url: job.url, sha256, mime_type });
+                manifest.save(&options.manifest_path)?;
+                report.downloaded += 1;
+            },
+            Err((attempts, error)) => report.failures.push(ScrapeFailure {
+                url: job.url,
+                path: job.path.to_string_lossy().into_owned(),
+                attempts,
+                error
+            }),
+        }
+    }
+
+    report.failures.sort_by(|a, b| a.path.cmp(&b.path));
+    write_json(&options.report_path, &report)?;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, env::temp_dir, fs::{create_dir_all, read, remove_dir_all, write}};
+
+    use rocket::http::hyper::body::Bytes;
+
+    use crate::model::scrapers::scraper_helpers::Download;
+    use crate::model::scrapers::stub_server::{StubResponse, StubServer, SAMPLE_FILE_PATH};
+    use super::*;
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Serves bodies from memory, any other url fails.
+    struct FakeDownloader {
+        bodies: HashMap<String, Vec<u8>>,
+    }
+
+    #[rocket::async_trait]
+    impl Downloader for FakeDownloader {
+        async fn fetch(&self, url: &str) -> Result<Download> {
+            match self.bodies.get(url) {
+                Some(body) => Ok(Download { bytes: Bytes::from(body.clone()), content_type: None }),
+                None => Err(format!("no body for {}", url).into()),
+            }
+        }
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let folder = temp_dir().join(format!("dominion_scrape_engine_{}_{}", name, std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    fn test_options(folder: &Path) -> ScrapeOptions {
+        ScrapeOptions {
+            concurrency: 2,
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(1),
+            image_bounds: ImageBounds::default(),
+            manifest_path: folder.join("manifest.json"),
+            report_path: folder.join("report.json"),
+        }
+    }
+
+    #[test]
+    fn test_file_checksum() {
+        let folder = test_dir("checksum");
+        write(folder.join("file.txt"), "abc").unwrap();
+        assert_eq!(
+            file_checksum(&folder.join("file.txt")).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_manifest_missing_is_empty() {
+        let folder = test_dir("manifest_missing");
+        assert_eq!(Manifest::load(&folder.join("manifest.json")).unwrap(), Manifest::default());
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let folder = test_dir("manifest_round_trip");
+        let mut manifest = Manifest::default();
+        manifest.files.insert("cards/Chapel_1.jpeg".to_string(), ManifestEntry {
+            url: "http://a".to_string(),
+            sha256: "00".to_string(),
+            mime_type: Some("image/jpeg".to_string())
+        });
+        manifest.save(&folder.join("manifest.json")).unwrap();
+        assert_eq!(Manifest::load(&folder.join("manifest.json")).unwrap(), manifest);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_manifest_key_is_relative() {
+        let manifest_path = Path::new("scraped_data/manifest.json");
+        assert_eq!(manifest_key(manifest_path, Path::new("scraped_data/cards/Chapel_1.jpeg")), "cards/Chapel_1.jpeg");
+        assert_eq!(manifest_key(manifest_path, Path::new("elsewhere/Chapel_1.jpeg")), "elsewhere/Chapel_1.jpeg");
+    }
+
+    #[tokio::test]
+    async fn test_run_scrape_skips_files_in_manifest() {
+        let folder = test_dir("skips");
+        let options = test_options(&folder);
+        let job = DownloadJob { url: "http://127.0.0.1:1/Chapel.jpg".to_string(), path: folder.join("cards/Chapel_1.jpeg") };
+        create_dir_all(folder.join("cards")).unwrap();
+        write(&job.path, "chapel").unwrap();
+        let mut manifest = Manifest::default();
+        manifest.files.insert("cards/Chapel_1.jpeg".to_string(), ManifestEntry {
+            url: job.url.clone(),
+            sha256: file_checksum(&job.path).unwrap(),
+            mime_type: None
+        });
+        manifest.save(&options.manifest_path).unwrap();
+
+        let report = run_scrape(vec![job], &options).await.unwrap();
+        assert_eq!(report, ScrapeReport { downloaded: 0, skipped: 1, failures: vec![] });
+        let _ = remove_dir_all(folder);
+    }
+
+    #[tokio::test]
+    async fn test_run_scrape_reports_failures_after_retries() {
+        let folder = test_dir("failures");
+        let options = test_options(&folder);
+        let changed_file = DownloadJob { url: "http://127.0.0.1:1/Moat.jpg".to_string(), path: folder.join("Moat_1.jpeg") };
+        write(&changed_file.path, "changed on disk").unwrap();
+        let mut manifest = Manifest::default();
+        manifest.files.insert("Moat_1.jpeg".to_string(), ManifestEntry { url: changed_file.url.clone(), sha256: "00".to_string(), mime_type: None });
+        manifest.save(&options.manifest_path).unwrap();
+        let jobs = vec![
+            changed_file,
+            DownloadJob { url: "http://127.0.0.1:1/Chapel.jpg".to_string(), path: folder.join("Chapel_1.jpeg") },
+        ];
+
+        let report = run_scrape(jobs, &options).await.unwrap();
+        assert_eq!(report.downloaded, 0);
+        assert_eq!(report.skipped, 0);
+        assert_eq!(report.total(), 2);
+        assert_eq!(report.failures.len(), 2);
+        assert!(report.failures[0].path.ends_with("Chapel_1.jpeg"));
+        assert_eq!(report.failures[0].attempts, 2);
+
+        let written: ScrapeReport = serde_json::from_slice(&fs::read(&options.report_path).unwrap()).unwrap();
+        assert_eq!(written, report);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[tokio::test]
+    async fn test_run_scrape_with_fake_downloader() {
+        let folder = test_dir("fake_downloader");
+        let options = test_options(&folder);
+        let copper = read("src/model/scraped_data/cards/Copper_1.jpeg").unwrap();
+        let downloader = FakeDownloader { bodies: HashMap::from([
+            ("fake://Chapel.jpg".to_string(), copper.clone()),
+            ("fake://Moat.jpg".to_string(), b"<!DOCTYPE html><title>Not Found</title>".to_vec()),
+        ]) };
+        let jobs = vec![
+            DownloadJob { url: "fake://Chapel.jpg".to_string(), path: folder.join("cards/Chapel_1.jpeg") },
+            DownloadJob { url: "fake://Moat.jpg".to_string(), path: folder.join("cards/Moat_1.jpeg") },
+            DownloadJob { url: "fake://Village.jpg".to_string(), path: folder.join("cards/Village_1.jpeg") },
+        ];
+
+        let report = run_scrape_with(jobs, &options, Arc::new(downloader)).await.unwrap();
+        assert_eq!(report.downloaded, 1);
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failures[0].error, "Downloaded an HTML page instead of a file.");
+        assert_eq!(report.failures[1].error, "no body for fake://Village.jpg");
+        assert_eq!(read(folder.join("cards/Chapel_1.jpeg")).unwrap(), copper);
+        assert!(!folder.join("cards/Moat_1.jpeg").exists());
+        let manifest = Manifest::load(&options.manifest_path).unwrap();
+        assert_eq!(manifest.mime_type(&options.manifest_path, &folder.join("cards/Chapel_1.jpeg")), Some("image/jpeg"));
+        let _ = remove_dir_all(folder);
+    }
+
+    #[tokio::test]
+    async fn test_run_scrape_retries_until_downloaded() {
+        let server = StubServer::start(vec![
+            ("/Chapel.jpg", vec![StubResponse::status(503), StubResponse::sample_file()]),
+        ]).await;
+        let folder = test_dir("retries");
+        let options = test_options(&folder);
+        let job = DownloadJob { url: server.url("/Chapel.jpg"), path: folder.join("cards/Chapel_1.svg") };
+
+        let report = run_scrape(vec![job.clone()], &options).await.unwrap();
+        assert_eq!(report, ScrapeReport { downloaded: 1, skipped: 0, failures: vec![] });
+        assert_eq!(server.request_count("/Chapel.jpg"), 2);
+        let manifest = Manifest::load(&options.manifest_path).unwrap();
+        assert_eq!(manifest.files["cards/Chapel_1.svg"], ManifestEntry {
+            url: job.url.clone(),
+            sha256: file_checksum(Path::new(SAMPLE_FILE_PATH)).unwrap(),
+            mime_type: Some("image/svg+xml".to_string())
+        });
+
+        let report = run_scrape(vec![job], &options).await.unwrap();
+        assert_eq!(report, ScrapeReport { downloaded: 0, skipped: 1, failures: vec![] });
+        assert_eq!(server.request_count("/Chapel.jpg"), 2);
+        let _ = remove_dir_all(folder);
+    }
+
+    fn downloaded_job(folder: &Path, options: &ScrapeOptions, name: &str) -> DownloadJob {
+        let job = DownloadJob { url: format!("http://127.0.0.1:1/{}.jpg", name), path: folder.join(format!("cards/{}_1.jpeg", name)) };
+        create_dir_all(folder.join("cards")).unwrap();
+        write(&job.path, name).unwrap();
+        let mut manifest = Manifest::load(&options.manifest_path).unwrap();
+        manifest.files.insert(format!("cards/{}_1.jpeg", name), ManifestEntry {
+            url: job.url.clone(),
+            sha256: file_checksum(&job.path).unwrap(),
+            mime_type: None
+        });
+        manifest.save(&options.manifest_path).unwrap();
+        job
+    }
+
+    #[test]
+    fn test_pending_jobs() {
+        let folder = test_dir("pending");
+        let options = test_options(&folder);
+        let chapel = downloaded_job(&folder, &options, "Chapel");
+        let moat = DownloadJob { url: "http://127.0.0.1:1/Moat.jpg".to_string(), path: folder.join("cards/Moat_1.jpeg") };
+        assert_eq!(pending_jobs(vec![chapel, moat.clone()], &options.manifest_path).unwrap(), vec![moat]);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_verify_files() {
+        let folder = test_dir("verify");
+        let options = test_options(&folder);
+        let chapel = downloaded_job(&folder, &options, "Chapel");
+        let moat = downloaded_job(&folder, &options, "Moat");
+        write(&moat.path, "changed on disk").unwrap();
+        let village = DownloadJob { url: "http://127.0.0.1:1/Village.jpg".to_string(), path: folder.join("cards/Village_1.jpeg") };
+        let witch = DownloadJob { url: "http://127.0.0.1:1/Witch.jpg".to_string(), path: folder.join("cards/Witch_1.jpeg") };
+        write(&witch.path, "witch").unwrap();
+
+        let problems = verify_files(&[chapel, moat.clone(), village.clone(), witch.clone()], &options.manifest_path).unwrap();
+        assert_eq!(problems, vec![
+            VerifyProblem { path: moat.path, kind: VerifyProblemKind::ChecksumMismatch },
+            VerifyProblem { path: village.path, kind: VerifyProblemKind::Missing },
+            VerifyProblem { path: witch.path, kind: VerifyProblemKind::NotInManifest },
+        ]);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_prune_files() {
+        let folder = test_dir("prune");
+        let options = test_options(&folder);
+        let chapel = downloaded_job(&folder, &options, "Chapel");
+        let moat = downloaded_job(&folder, &options, "Moat");
+        write(&options.report_path, "{}").unwrap();
+        // drafts a --draft-dir inside the output directory put there
+        let draft = folder.join(DRAFT_FOLDER_NAME).join("intrigue_cards.json");
+        create_dir_all(draft.parent().unwrap()).unwrap();
+        write(&draft, "[]").unwrap();
+
+        let jobs = vec![chapel.clone()];
+        assert_eq!(prune_files(&jobs, &options, true).unwrap(), vec![moat.path.clone()]);
+        assert!(moat.path.exists());
+        assert_eq!(Manifest::load(&options.manifest_path).unwrap().files.len(), 2);
+
+        assert_eq!(prune_files(&jobs, &options, false).unwrap(), vec![moat.path.clone()]);
+        assert!(!moat.path.exists());
+        assert!(chapel.path.exists());
+        assert!(options.report_path.exists());
+        assert!(draft.exists());
+        let manifest = Manifest::load(&options.manifest_path).unwrap();
+        assert_eq!(manifest.files.keys().collect::<Vec<_>>(), vec!["cards/Chapel_1.jpeg"]);
+        let _ = remove_dir_all(folder);
+    }
+}
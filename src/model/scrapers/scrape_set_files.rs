@@ -1,22 +1,28 @@
+use std::path::Path;
+
 use crate::model::{set::{Set, SetFilePaths}};
 
-use super::scraper_helpers::download_file_to_path;
+use super::{image_normaliser::ImageBounds, scrape_engine::DownloadJob, scraper_helpers::{Downloader, download_file_to_path}};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
 
-#[allow(dead_code)]
-pub(crate) async fn scrape_set_files(set: &Set) -> Result<()> {
+/// The cover art, icon and rule book of every edition of the set, saved to a folder per edition in the output directory.
+pub(crate) fn set_download_jobs(set: &Set, output_dir: &Path) -> Vec<DownloadJob> {
+    let mut jobs = vec![];
     for (index, edition) in set.editions.iter().enumerate() {
-        download_file_to_path(
-            &set.get_cover_art_path(*edition),
-            set.cover_art_url.get(index).unwrap()).await?;
-        download_file_to_path(
-            &set.get_icon_path(*edition),
-            set.icon_url.get(index).unwrap()).await?;
-        download_file_to_path(
-            &set.get_rule_book_path(*edition),
-            set.rule_book_url.get(index).unwrap()).await?;
+        let folder = output_dir.join(set.get_folder_name(*edition));
+        jobs.push(DownloadJob { url: set.cover_art_url[index].clone(), path: folder.join("cover.png") });
+        jobs.push(DownloadJob { url: set.icon_url[index].clone(), path: folder.join("icon.png") });
+        jobs.push(DownloadJob { url: set.rule_book_url[index].clone(), path: folder.join("rules.pdf") });
+    }
+    jobs
+}
+
+#[allow(dead_code)]
+pub(crate) async fn scrape_set_files(downloader: &dyn Downloader, set: &Set, output_dir: &Path) -> Result<()> {
+    for job in set_download_jobs(set, output_dir) {
+        download_file_to_path(downloader, &job.path.to_string_lossy(), &job.url, ImageBounds::default()).await?;
     }
     Ok(())
 } 
@@ -24,47 +30,43 @@ pub(crate) async fn scrape_set_files(set: &Set) -> Result<()> {
 
 #[cfg(test)]
 mod test {
-    use std::fs::remove_dir_all;
+    use std::{env::temp_dir, fs::{read, remove_dir_all}};
 
     use tokio;
-    use file_diff::diff;
     use crate::model::set::{Set, SetFilePaths};
+    use crate::model::scrapers::{scraper_helpers::HttpDownloader, stub_server::{StubResponse, StubServer}};
     use super::scrape_set_files;
 
     #[tokio::test]
     async fn test_scrape_set_files() {
-        let _ = remove_dir_all("src\\model\\scraped_data\\test-name-one_13");
-        let _ = remove_dir_all("src\\model\\scraped_data\\test-name-one_21");
-        let logo_url = "https://github.githubassets.com/favicons/favicon.svg";
+        let png = read("src/model/scraped_data/Dominion_1/icon.png").unwrap();
+        let pdf = b"%PDF-1.4 rules".to_vec();
+        let server = StubServer::start(vec![
+            ("/logo.png", vec![StubResponse::with_content_type("image/png", &png)]),
+            ("/rules.pdf", vec![StubResponse::with_content_type("application/pdf", &pdf)]),
+        ]).await;
+        let output_dir = temp_dir().join(format!("dominion_scrape_set_files_{}", std::process::id()));
+        let _ = remove_dir_all(&output_dir);
+        let (logo_url, rules_url) = (server.url("/logo.png"), server.url("/rules.pdf"));
         let set = Set::new( 
             1, 
             "test name'one".to_string(),
             vec![13, 21], 
-            vec![logo_url.to_string(), logo_url.to_string()], 
-            vec![logo_url.to_string(), logo_url.to_string()],  
-            vec![logo_url.to_string(), logo_url.to_string()], 
+            vec![logo_url.clone(), logo_url.clone()], 
+            vec![rules_url.clone(), rules_url.clone()],  
+            vec![logo_url.clone(), logo_url.clone()], 
         );
+        scrape_set_files(&HttpDownloader::default(), &set, &output_dir).await.unwrap();
 
-        let files = [
-            set.get_cover_art_path(13),
-            set.get_rule_book_path(13),
-            set.get_icon_path(13),
-            set.get_cover_art_path(21),
-            set.get_rule_book_path(21),
-            set.get_icon_path(21),
-        ];
-        let _ = scrape_set_files(&set).await;
-
-        let expected_sample_file_path = "src/model/scrapers/sample_files/github.svg";
-
-
-        for file in files {
-            println!("{}", file);
-            assert!(diff(expected_sample_file_path, &file));
+        for edition in [13, 21] {
+            let folder = output_dir.join(set.get_folder_name(edition));
+            assert_eq!(read(folder.join("cover.png")).unwrap(), png);
+            assert_eq!(read(folder.join("icon.png")).unwrap(), png);
+            assert_eq!(read(folder.join("rules.pdf")).unwrap(), pdf);
         }
-        let _ = remove_dir_all("src\\model\\scraped_data\\test-name-one_13");
-        let _ = remove_dir_all("src\\model\\scraped_data\\test-name-one_21");
-
+        assert!(output_dir.join("test-name-one_21/rules.pdf").exists());
+        assert_eq!(server.request_count("/logo.png"), 4);
+        assert_eq!(server.request_count("/rules.pdf"), 2);
+        let _ = remove_dir_all(output_dir);
     }
-
 }
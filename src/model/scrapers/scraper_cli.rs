@@ -0,0 +1,182 @@
+use std::{error::Error, fmt, path::PathBuf};
+
+use super::{scrape_engine::DEFAULT_OUTPUT_DIR, wiki_cards::DRAFT_FOLDER_NAME};
+
+pub const USAGE: &str = "\
+Usage: scrapers <command> [options]
+
+Commands:
+  sets          download the cover art, icon and rule book of every set
+  cards         download the art of every card
+  card <name>   download the art of one card
+  set <id>      download the files of one set
+  verify        check every file is downloaded and matches the manifest
+  prune         delete downloaded files no set or card uses
+  wiki <dump>   draft card JSON from a saved wiki export and show how it differs from raw_data
+
+Options:
+  --dry-run              list what would be downloaded, deleted or drafted without changing anything
+  --output-dir <path>    where files are saved, defaults to src/model/scraped_data
+  --draft-dir <path>     where wiki drafts are written, defaults to wiki_drafts
+  --concurrency <count>  how many files are downloaded at once, defaults to 4";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ScraperCommand {
+    Sets,
+    Cards,
+    Card(String),
+    Set(u8),
+    Verify,
+    Prune,
+    Wiki(PathBuf),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScraperArgs {
+    pub command: ScraperCommand,
+    pub dry_run: bool,
+    pub output_dir: PathBuf,
+    pub draft_dir: PathBuf,
+    pub concurrency: usize,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ScraperArgsError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownOption(String),
+    MissingValue(String),
+    InvalidValue { name: String, value: String },
+    UnexpectedArgument(String),
+}
+
+impl fmt::Display for ScraperArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScraperArgsError::MissingCommand => write!(f, "A command is required."),
+            ScraperArgsError::UnknownCommand(command) => write!(f, "Unknown command {}.", command),
+            ScraperArgsError::UnknownOption(option) => write!(f, "Unknown option {}.", option),
+            ScraperArgsError::MissingValue(name) => write!(f, "{} needs a value.", name),
+            ScraperArgsError::InvalidValue { name, value } => write!(f, "{} is not a valid value for {}.", value, name),
+            ScraperArgsError::UnexpectedArgument(argument) => write!(f, "Unexpected argument {}.", argument),
+        }
+    }
+}
+
+impl Error for ScraperArgsError {}
+
+fn parse_value<T: std::str::FromStr>(name: &str, value: Option<String>) -> Result<T, ScraperArgsError> {
+    let value = value.ok_or_else(|| ScraperArgsError::MissingValue(name.to_string()))?;
+    value.parse().map_err(|_| ScraperArgsError::InvalidValue { name: name.to_string(), value })
+}
+
+/// Parses the arguments after the program name, options can come before or after the command.
+pub fn parse_scraper_args(args: impl IntoIterator<Item = String>) -> Result<ScraperArgs, ScraperArgsError> {
+    let mut args = args.into_iter();
+    let mut positional = vec![];
+    let mut dry_run = false;
+    let mut output_dir = PathBuf::from(DEFAULT_OUTPUT_DIR);
+    let mut draft_dir = PathBuf::from(DRAFT_FOLDER_NAME);
+    let mut concurrency = 4;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--output-dir" => output_dir = parse_value(&arg, args.next())?,
+            "--draft-dir" => draft_dir = parse_value(&arg, args.next())?,
+            "--concurrency" => {
+                concurrency = parse_value(&arg, args.next())?;
+                if concurrency == 0 {
+                    return Err(ScraperArgsError::InvalidValue { name: arg, value: "0".to_string() });
+                }
+            },
+            option if option.starts_with("--") => return Err(ScraperArgsError::UnknownOption(arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let command = match positional.next().as_deref() {
+        None => return Err(ScraperArgsError::MissingCommand),
+        Some("sets") => ScraperCommand::Sets,
+        Some("cards") => ScraperCommand::Cards,
+        Some("verify") => ScraperCommand::Verify,
+        Some("prune") => ScraperCommand::Prune,
+        Some("card") => ScraperCommand::Card(positional.next().ok_or_else(|| ScraperArgsError::MissingValue("card".to_string()))?),
+        Some("set") => ScraperCommand::Set(parse_value("set", positional.next())?),
+        Some("wiki") => ScraperCommand::Wiki(parse_value("wiki", positional.next())?),
+        Some(command) => return Err(ScraperArgsError::UnknownCommand(command.to_string())),
+    };
+    if let Some(argument) = positional.next() {
+        return Err(ScraperArgsError::UnexpectedArgument(argument));
+    }
+
+    Ok(ScraperArgs { command, dry_run, output_dir, draft_dir, concurrency })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<ScraperArgs, ScraperArgsError> {
+        parse_scraper_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(parse(&["sets"]).unwrap().command, ScraperCommand::Sets);
+        assert_eq!(parse(&["cards"]).unwrap().command, ScraperCommand::Cards);
+        assert_eq!(parse(&["card", "Throne Room"]).unwrap().command, ScraperCommand::Card("Throne Room".to_string()));
+        assert_eq!(parse(&["set", "2"]).unwrap().command, ScraperCommand::Set(2));
+        assert_eq!(parse(&["verify"]).unwrap().command, ScraperCommand::Verify);
+        assert_eq!(parse(&["prune"]).unwrap().command, ScraperCommand::Prune);
+        assert_eq!(parse(&["wiki", "dump.xml"]).unwrap().command, ScraperCommand::Wiki(PathBuf::from("dump.xml")));
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        assert_eq!(parse(&["cards"]).unwrap(), ScraperArgs {
+            command: ScraperCommand::Cards,
+            dry_run: false,
+            output_dir: PathBuf::from(DEFAULT_OUTPUT_DIR),
+            draft_dir: PathBuf::from(DRAFT_FOLDER_NAME),
+            concurrency: 4
+        });
+    }
+
+    #[test]
+    fn test_parse_options_before_and_after_command() {
+        let args = parse(&["--dry-run", "card", "Moat", "--output-dir", "/tmp/scraped", "--concurrency", "8"]).unwrap();
+        assert_eq!(args, ScraperArgs {
+            command: ScraperCommand::Card("Moat".to_string()),
+            dry_run: true,
+            output_dir: PathBuf::from("/tmp/scraped"),
+            draft_dir: PathBuf::from(DRAFT_FOLDER_NAME),
+            concurrency: 8
+        });
+        assert_eq!(parse(&["wiki", "dump.xml", "--draft-dir", "/tmp/drafts"]).unwrap().draft_dir, PathBuf::from("/tmp/drafts"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse(&[]), Err(ScraperArgsError::MissingCommand));
+        assert_eq!(parse(&["everything"]), Err(ScraperArgsError::UnknownCommand("everything".to_string())));
+        assert_eq!(parse(&["sets", "--fast"]), Err(ScraperArgsError::UnknownOption("--fast".to_string())));
+        assert_eq!(parse(&["card"]), Err(ScraperArgsError::MissingValue("card".to_string())));
+        assert_eq!(parse(&["wiki"]), Err(ScraperArgsError::MissingValue("wiki".to_string())));
+        assert_eq!(parse(&["set", "x"]), Err(ScraperArgsError::InvalidValue { name: "set".to_string(), value: "x".to_string() }));
+        assert_eq!(parse(&["sets", "--output-dir"]), Err(ScraperArgsError::MissingValue("--output-dir".to_string())));
+        assert_eq!(
+            parse(&["sets", "--concurrency", "0"]),
+            Err(ScraperArgsError::InvalidValue { name: "--concurrency".to_string(), value: "0".to_string() }));
+        assert_eq!(parse(&["sets", "cards"]), Err(ScraperArgsError::UnexpectedArgument("cards".to_string())));
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(ScraperArgsError::UnknownCommand("x".to_string()).to_string(), "Unknown command x.");
+        assert_eq!(
+            ScraperArgsError::InvalidValue { name: "set".to_string(), value: "x".to_string() }.to_string(),
+            "x is not a valid value for set.");
+    }
+}
@@ -1,16 +1,78 @@
-use std::{fs::{File, create_dir_all}, io::{self, Cursor}, path::Path};
+use std::{fs::{File, create_dir_all}, io::{self, Cursor}, path::Path, time::Duration};
 use reqwest;
+use rocket::http::hyper::body::Bytes;
+
+use super::{image_normaliser::{ImageBounds, normalise_download}, media_type::MediaType};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
-#[allow(dead_code)]
-pub(crate) async fn download_file_to_path(file_path_str: &str, image_url: &str) -> Result<()> {
-        let request_bytes = reqwest::get(image_url).await?.bytes().await?;
-        save_byes_to_file(file_path_str, request_bytes)?;
-        Ok(())
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+// long enough for the largest rule book
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A fetched body and the Content-Type it was sent with.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Download {
+    pub bytes: Bytes,
+    pub content_type: Option<String>,
+}
+
+/// Fetches the body behind a url, the scrapers download through this so tests can swap the network out.
+#[rocket::async_trait]
+pub trait Downloader: Send + Sync {
+    async fn fetch(&self, url: &str) -> Result<Download>;
+}
+
+/// Downloads over HTTP, an error status such as a 404 is a failed download.
+/// A server that stalls fails the download after the timeout so it is retried rather than holding up the scrape.
+#[derive(Debug, Clone)]
+pub struct HttpDownloader {
+    client: reqwest::Client,
+}
+
+impl HttpDownloader {
+    pub fn with_timeouts(connect_timeout: Duration, request_timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(connect_timeout)
+            .timeout(request_timeout)
+            .build()
+            .expect("a client with only timeouts set always builds");
+        Self { client }
+    }
+}
+
+impl Default for HttpDownloader {
+    fn default() -> Self {
+        Self::with_timeouts(CONNECT_TIMEOUT, REQUEST_TIMEOUT)
+    }
+}
+
+#[rocket::async_trait]
+impl Downloader for HttpDownloader {
+    async fn fetch(&self, url: &str) -> Result<Download> {
+        let response = self.client.get(url).send().await?.error_for_status()?;
+        let content_type = response.headers().get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(str::to_string);
+        Ok(Download { bytes: response.bytes().await?, content_type })
+    }
+}
+
+/// Downloads a file, checks it is what the path's extension says and saves it, returns what the saved file really is.
+pub(crate) async fn download_file_to_path(
+    downloader: &dyn Downloader,
+    file_path_str: &str,
+    image_url: &str,
+    bounds: ImageBounds
+) -> Result<MediaType> {
+        let download = downloader.fetch(image_url).await?;
+        let target = MediaType::from_path(Path::new(file_path_str));
+        let normalised = normalise_download(&download.bytes, download.content_type.as_deref(), target, bounds)?;
+        save_byes_to_file(file_path_str, Bytes::from(normalised.bytes))?;
+        Ok(normalised.media_type)
 }
 
-fn save_byes_to_file(file_path_str: &str, mut bytes: rocket::http::hyper::body::Bytes) -> Result<()> {
+fn save_byes_to_file(file_path_str: &str, mut bytes: Bytes) -> Result<()> {
     let file_path = Path::new(file_path_str);
     if let Some(parent) = file_path.parent() { create_dir_all(parent)? }
     let mut file_pointer = File::create(file_path)?;
@@ -21,23 +83,84 @@ fn save_byes_to_file(file_path_str: &str, mut bytes: rocket::http::hyper::body::
 
 #[cfg(test)]
 mod test {
-    use tokio;
+    use tokio::{self, net::TcpListener};
     use file_diff::diff;
-    use std::fs::remove_dir_all;
+    use std::{env::temp_dir, fs::{read, remove_dir_all}, time::{Duration, Instant}};
 
-    use super::download_file_to_path;
+    use crate::model::scrapers::{image_normaliser::ImageBounds, media_type::MediaType, stub_server::{StubResponse, StubServer, SAMPLE_FILE_PATH}};
+    use super::{Downloader, HttpDownloader, download_file_to_path};
 
     #[tokio::test]
     async fn test_download_file_to_path() {
-        let logo_url = "https://github.githubassets.com/favicons/favicon.svg";
-        let expected_sample_file_path = "src/model/scrapers/sample_files/github.svg";
-        let download_folder = "src/model/scrapers/sample_files/download";
-        let file_path = format!("{}/downloaded.svg", download_folder);
+        let server = StubServer::start(vec![("/favicon.svg", vec![StubResponse::sample_file()])]).await;
+        let download_folder = temp_dir().join(format!("dominion_scraper_helpers_{}", std::process::id()));
+        let file_path = download_folder.join("downloaded.svg");
+
+        let _ = remove_dir_all(&download_folder);
+        let media_type = download_file_to_path(
+            &HttpDownloader::default(),
+            &file_path.to_string_lossy(),
+            &server.url("/favicon.svg"),
+            ImageBounds::default()
+        ).await.unwrap();
 
+        assert_eq!(media_type, MediaType::Svg);
+        assert!(diff(SAMPLE_FILE_PATH, &file_path.to_string_lossy()));
+        assert_eq!(server.request_count("/favicon.svg"), 1);
         let _ = remove_dir_all(download_folder);
-        let _ = download_file_to_path(&file_path, logo_url).await;
-        
-        assert!(diff(expected_sample_file_path, &file_path));
+    }
+
+    #[tokio::test]
+    async fn test_http_downloader_fetch() {
+        let server = StubServer::start(vec![("/favicon.svg", vec![StubResponse::sample_file()])]).await;
+        let download = HttpDownloader::default().fetch(&server.url("/favicon.svg")).await.unwrap();
+        assert_eq!(download.bytes.to_vec(), read(SAMPLE_FILE_PATH).unwrap());
+        assert_eq!(download.content_type.as_deref(), Some("image/svg+xml"));
+    }
+
+    #[tokio::test]
+    async fn test_download_file_to_path_rejects_html() {
+        let server = StubServer::start(vec![
+            ("/Chapel.jpg", vec![StubResponse::with_content_type("text/html", b"<html><body>No such file</body></html>")]),
+        ]).await;
+        let download_folder = temp_dir().join(format!("dominion_scraper_helpers_html_{}", std::process::id()));
+        let file_path = download_folder.join("Chapel_1.jpeg");
+
+        let result = download_file_to_path(
+            &HttpDownloader::default(),
+            &file_path.to_string_lossy(),
+            &server.url("/Chapel.jpg"),
+            ImageBounds::default()
+        ).await;
+        assert_eq!(result.unwrap_err().to_string(), "Downloaded an HTML page instead of a file.");
+        assert!(!file_path.exists());
         let _ = remove_dir_all(download_folder);
     }
+
+    #[tokio::test]
+    async fn test_http_downloader_times_out() {
+        // accepts connections and never answers them
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut streams = vec![];
+            while let Ok((stream, _)) = listener.accept().await {
+                streams.push(stream);
+            }
+        });
+
+        let downloader = HttpDownloader::with_timeouts(Duration::from_secs(1), Duration::from_millis(200));
+        let started = Instant::now();
+        assert!(downloader.fetch(&format!("http://{}/stalled.svg", address)).await.is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_http_downloader_error_status() {
+        let server = StubServer::start(vec![("/gone.svg", vec![StubResponse::status(500)])]).await;
+        let downloader = HttpDownloader::default();
+        assert!(downloader.fetch(&server.url("/gone.svg")).await.is_err());
+        assert!(downloader.fetch(&server.url("/missing.svg")).await.is_err());
+    }
 }
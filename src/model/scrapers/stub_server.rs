@@ -0,0 +1,115 @@
+use std::{collections::HashMap, net::SocketAddr, sync::{Arc, Mutex}};
+
+use tokio::{io::{AsyncReadExt, AsyncWriteExt}, net::{TcpListener, TcpStream}, task::JoinHandle};
+
+pub const SAMPLE_FILE_PATH: &str = "src/model/scrapers/sample_files/github.svg";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StubResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl StubResponse {
+    pub fn ok(body: &[u8]) -> Self {
+        Self { status: 200, content_type: None, body: body.to_vec() }
+    }
+
+    pub fn with_content_type(content_type: &str, body: &[u8]) -> Self {
+        Self { status: 200, content_type: Some(content_type.to_string()), body: body.to_vec() }
+    }
+
+    pub fn status(status: u16) -> Self {
+        Self { status, content_type: None, body: vec![] }
+    }
+
+    /// The svg the scraper tests expect to find on disk after a download.
+    pub fn sample_file() -> Self {
+        Self::with_content_type("image/svg+xml", &std::fs::read(SAMPLE_FILE_PATH).expect("the sample file is checked in"))
+    }
+}
+
+/// A local HTTP server for the scraper tests, so they run without the network.
+/// Each path answers with its responses in order and then keeps repeating the last one, any other path is a 404.
+pub struct StubServer {
+    address: SocketAddr,
+    request_counts: Arc<Mutex<HashMap<String, usize>>>,
+    handle: JoinHandle<()>,
+}
+
+impl StubServer {
+    pub async fn start(routes: Vec<(&str, Vec<StubResponse>)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("a free local port");
+        let address = listener.local_addr().expect("the bound address");
+        let routes: Arc<HashMap<String, Vec<StubResponse>>> = Arc::new(routes.into_iter()
+            .map(|(path, responses)| (path.to_string(), responses))
+            .collect());
+        let request_counts = Arc::new(Mutex::new(HashMap::new()));
+
+        let counts = request_counts.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (routes, counts) = (routes.clone(), counts.clone());
+                tokio::spawn(async move {
+                    let _ = respond(stream, &routes, &counts).await;
+                });
+            }
+        });
+        Self { address, request_counts, handle }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.address, path)
+    }
+
+    pub fn request_count(&self, path: &str) -> usize {
+        self.request_counts.lock().unwrap().get(path).copied().unwrap_or(0)
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn respond(
+    mut stream: TcpStream,
+    routes: &HashMap<String, Vec<StubResponse>>,
+    request_counts: &Mutex<HashMap<String, usize>>
+) -> std::io::Result<()> {
+    let mut request = vec![];
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+    }
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+    let request_number = {
+        let mut request_counts = request_counts.lock().unwrap();
+        let count = request_counts.entry(path.clone()).or_insert(0);
+        *count += 1;
+        *count - 1
+    };
+    let response = routes.get(&path)
+        .and_then(|responses| responses.get(request_number).or(responses.last()))
+        .cloned()
+        .unwrap_or(StubResponse::status(404));
+
+    let content_type = response.content_type.map(|content_type| format!("Content-Type: {}\r\n", content_type)).unwrap_or_default();
+    let head = format!(
+        "HTTP/1.1 {} Stub\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        content_type,
+        response.body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&response.body).await?;
+    stream.shutdown().await
+}
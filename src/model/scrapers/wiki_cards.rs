@@ -0,0 +1,618 @@
+use std::{collections::{BTreeMap, BTreeSet, HashSet}, error::Error, fmt, fs, io::{self, ErrorKind}, path::{Path, PathBuf}, sync::LazyLock};
+
+use regex::Regex;
+use rocket::serde::{Serialize, json::{Value, serde_json}};
+
+use crate::model::{
+    card::Card,
+    card_relationships::CardRelationships,
+    card_text::CardText,
+    card_type::CardType,
+    card_validation::BASIC_CARD_SET_ID,
+    cost::Cost,
+    data_loaders::card_data_loader::{BASE_CARD_FILE_NAME, set_card_file_name, set_card_id_range},
+    set::Set
+};
+
+use super::wiki_dump::{WikiPage, parse_wiki_dump, template_fields, wiki_image_url, wiki_markup_to_text};
+
+pub const CARD_INFOBOX: &str = "Infobox Card";
+// kept out of the scraped data so `prune` never sees the drafts and they stay out of the source tree
+pub const DRAFT_FOLDER_NAME: &str = "wiki_drafts";
+
+static COST_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\$?(\d+)?(P)?(?:(\d+)D)?$").unwrap());
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WikiCardError {
+    MissingField { page: String, field: String },
+    UnknownSet { page: String, set_name: String },
+    UnknownCardType { page: String, card_type: String },
+    InvalidCost { page: String, cost: String },
+    UnknownEdition { page: String, edition: String },
+    NoFreeCardId { page: String, set_id: u8 },
+    DuplicateCard { page: String },
+}
+
+impl fmt::Display for WikiCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WikiCardError::MissingField { page, field } => write!(f, "Page {} has no {}.", page, field),
+            WikiCardError::UnknownSet { page, set_name } => write!(f, "Page {} has unknown set {}.", page, set_name),
+            WikiCardError::UnknownCardType { page, card_type } => write!(f, "Page {} has unknown card type {}.", page, card_type),
+            WikiCardError::InvalidCost { page, cost } => write!(f, "Page {} has a cost {} that could not be read.", page, cost),
+            WikiCardError::UnknownEdition { page, edition } => write!(f, "Page {} has edition {} which its set does not.", page, edition),
+            WikiCardError::NoFreeCardId { page, set_id } => write!(f, "Page {} has no free card id left in set {}.", page, set_id),
+            WikiCardError::DuplicateCard { page } => write!(f, "Page {} is a card an earlier page already drafted.", page),
+        }
+    }
+}
+
+impl Error for WikiCardError {}
+
+/// The card fields read from a page, everything else is left for a maintainer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WikiCard {
+    pub name: String,
+    pub cost: Cost,
+    pub set_id: u8,
+    pub editions: Vec<u8>,
+    pub card_types: Vec<CardType>,
+    pub texts: Vec<CardText>,
+    pub art_url: Vec<String>,
+}
+
+/// One field that differs between a card in the raw data and its draft.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub existing: String,
+    pub draft: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CardDiff {
+    Added { name: String, id: u16 },
+    Changed { name: String, changes: Vec<FieldChange> },
+}
+
+impl fmt::Display for CardDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardDiff::Added { name, id } => write!(f, "+ {} (id {})", name, id),
+            CardDiff::Changed { name, changes } => {
+                write!(f, "~ {}", name)?;
+                for change in changes {
+                    write!(f, "\n    {}: {} -> {}", change.field, change.existing, change.draft)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// What a wiki dump turned into, cards with a problem are left out of `cards`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct WikiDraft {
+    pub pages: usize,
+    pub cards: Vec<Card>,
+    pub diffs: Vec<CardDiff>,
+    pub problems: Vec<WikiCardError>,
+}
+
+fn field<'a>(fields: &'a BTreeMap<String, String>, name: &str) -> Option<&'a str> {
+    fields.get(name).map(|value| value.trim()).filter(|value| !value.is_empty())
+}
+
+/// The wiki calls Dominion "Base" and marks later printings such as "Intrigue (Second Edition)".
+fn find_set<'a>(set_name: &str, sets: &'a [Set]) -> Option<&'a Set> {
+    let set_name = set_name.split('(').next().unwrap_or("").trim();
+    let set_name = if set_name.eq_ignore_ascii_case("base") { "Dominion" } else { set_name };
+    sets.iter().find(|set| set.name.eq_ignore_ascii_case(set_name))
+}
+
+fn parse_card_type(card_type: &str) -> Option<CardType> {
+    serde_json::from_value(Value::String(card_type.replace(' ', ""))).ok()
+}
+
+/// Reads a cost written the way the site prints it, `$4`, `$3P` or `8D`, with or without the wiki's cost templates.
+fn parse_cost(cost: &str) -> Option<Cost> {
+    let cost = wiki_markup_to_text(cost).replace(' ', "").replace("Potion", "P");
+    let captures = COST_PATTERN.captures(&cost).filter(|captures| (1..=3).any(|group| captures.get(group).is_some()))?;
+    Some(Cost {
+        coins: captures.get(1).map_or(Ok(0), |coins| coins.as_str().parse()).ok()?,
+        potion: captures.get(2).is_some(),
+        debt: captures.get(3).map_or(Ok(0), |debt| debt.as_str().parse()).ok()?,
+        modifiers: vec![],
+    })
+}
+
+/// Reads the card infobox of a page, pages without one are not cards and give `None`.
+/// `textN` and `imageN` give the wording and art of edition N, `text` and `image` are used for every other edition.
+pub fn parse_wiki_card(page: &WikiPage, sets: &[Set]) -> Result<Option<WikiCard>, WikiCardError> {
+    let fields = match template_fields(&page.text, CARD_INFOBOX) {
+        Some(fields) => fields,
+        None => return Ok(None),
+    };
+    let page_name = || page.title.clone();
+    let missing = |field: &str| WikiCardError::MissingField { page: page_name(), field: field.to_string() };
+
+    let name = field(&fields, "name").map(wiki_markup_to_text).unwrap_or_else(|| page.title.clone());
+    let set_name = field(&fields, "set").ok_or_else(|| missing("set"))?;
+    let set = find_set(&wiki_markup_to_text(set_name), sets)
+        .ok_or_else(|| WikiCardError::UnknownSet { page: page_name(), set_name: set_name.to_string() })?;
+
+    let cost_text = field(&fields, "cost").ok_or_else(|| missing("cost"))?;
+    let mut cost = parse_cost(cost_text).ok_or_else(|| WikiCardError::InvalidCost { page: page_name(), cost: cost_text.to_string() })?;
+    if let Some(debt) = field(&fields, "debt") {
+        cost.debt = debt.parse().map_err(|_| WikiCardError::InvalidCost { page: page_name(), cost: debt.to_string() })?;
+    }
+
+    let mut card_types = vec![];
+    for type_field in ["type", "type1", "type2", "type3", "type4"] {
+        if let Some(card_type) = field(&fields, type_field) {
+            let card_type = wiki_markup_to_text(card_type);
+            card_types.push(parse_card_type(&card_type)
+                .ok_or(WikiCardError::UnknownCardType { page: page_name(), card_type })?);
+        }
+    }
+    if card_types.is_empty() {
+        return Err(missing("type1"));
+    }
+
+    let editions = match field(&fields, "editions") {
+        Some(editions) => editions.split(',')
+            .map(|edition| edition.trim().parse::<u8>().ok()
+                .filter(|edition| set.editions.contains(edition))
+                .ok_or_else(|| WikiCardError::UnknownEdition { page: page_name(), edition: edition.trim().to_string() }))
+            .collect::<Result<Vec<u8>, WikiCardError>>()?,
+        None => set.editions.clone(),
+    };
+
+    let mut texts = vec![];
+    let mut art_url = vec![];
+    for edition in editions.iter() {
+        if let Some(text) = field(&fields, &format!("text{}", edition)).or(field(&fields, "text")) {
+            texts.push(CardText { edition: *edition, text: wiki_markup_to_text(text), errata: vec![], faq: vec![] });
+        }
+        let image = field(&fields, &format!("image{}", edition)).or(field(&fields, "image"))
+            .ok_or_else(|| missing("image"))?;
+        art_url.push(wiki_image_url(image));
+    }
+
+    Ok(Some(WikiCard { name, cost, set_id: set.id, editions, card_types, texts, art_url }))
+}
+
+/// Keeps the type and cost tags in step with the card's types and cost, other tags are left alone.
+fn sync_generated_tags(card: &mut Card) {
+    let generated: Vec<_> = card.card_types.iter().map(|card_type| card_type.type_tag()).chain(card.cost.cost_tag()).collect();
+    card.card_tags.retain(|card_tag| !(card_tag.is_type_tag() || card_tag.is_cost_tag()) || generated.contains(card_tag));
+    for card_tag in generated {
+        if !card.card_tags.contains(&card_tag) {
+            card.card_tags.push(card_tag);
+        }
+    }
+}
+
+fn new_card(wiki_card: WikiCard, id: u16) -> Card {
+    let card_counts = match wiki_card.card_types.contains(&CardType::Victory) {
+        true => [8, 12, 12, 12, 12],
+        false => [10, 10, 10, 10, 10],
+    };
+    let mut card = Card {
+        id,
+        name: wiki_card.name,
+        supply_card: true,
+        basic_card: false,
+        card_counts,
+        cost: wiki_card.cost,
+        set_id: wiki_card.set_id,
+        editions: wiki_card.editions,
+        card_tags: vec![],
+        kingdom_requirements: vec![],
+        kingdom_synergies: vec![],
+        kingdom_anti_synergies: vec![],
+        card_types: wiki_card.card_types,
+        art_url: wiki_card.art_url,
+        relationships: CardRelationships::default(),
+        texts: wiki_card.texts,
+    };
+    sync_generated_tags(&mut card);
+    card
+}
+
+fn next_free_id(set_id: u8, used_ids: &mut HashSet<u16>) -> Option<u16> {
+    let id = set_card_id_range(set_id).find(|id| !used_ids.contains(id))?;
+    used_ids.insert(id);
+    Some(id)
+}
+
+/// Card records for the wiki cards. A card already in `existing` keeps its id and the fields only a maintainer fills in,
+/// new cards and cards moved to another set get the next free id of their set. Basic cards stay in the basic set
+/// whatever set their page names. A second page for the same card name is reported rather than drafted twice.
+pub fn draft_cards(wiki_cards: Vec<WikiCard>, existing: &[Card]) -> (Vec<Card>, Vec<WikiCardError>) {
+    let mut used_ids: HashSet<u16> = existing.iter().map(|card| card.id).collect();
+    let mut drafted_names: HashSet<String> = HashSet::new();
+    let mut cards = vec![];
+    let mut problems = vec![];
+    for wiki_card in wiki_cards {
+        if !drafted_names.insert(wiki_card.name.to_lowercase()) {
+            problems.push(WikiCardError::DuplicateCard { page: wiki_card.name });
+            continue;
+        }
+        match existing.iter().find(|card| card.name.eq_ignore_ascii_case(&wiki_card.name)) {
+            Some(existing_card) => {
+                let mut card = existing_card.clone();
+                if card.set_id != BASIC_CARD_SET_ID && card.set_id != wiki_card.set_id {
+                    card.id = match next_free_id(wiki_card.set_id, &mut used_ids) {
+                        Some(id) => id,
+                        None => {
+                            problems.push(WikiCardError::NoFreeCardId { page: wiki_card.name, set_id: wiki_card.set_id });
+                            continue;
+                        },
+                    };
+                    card.set_id = wiki_card.set_id;
+                }
+                card.cost = Cost { modifiers: card.cost.modifiers.clone(), ..wiki_card.cost };
+                card.editions = wiki_card.editions;
+                card.card_types = wiki_card.card_types;
+                card.art_url = wiki_card.art_url;
+                card.texts = wiki_card.texts.into_iter()
+                    .map(|card_text| existing_card.texts.iter()
+                        .find(|existing_text| existing_text.edition == card_text.edition)
+                        .map(|existing_text| CardText { text: card_text.text.clone(), ..existing_text.clone() })
+                        .unwrap_or(card_text))
+                    .collect();
+                sync_generated_tags(&mut card);
+                cards.push(card);
+            },
+            None => match next_free_id(wiki_card.set_id, &mut used_ids) {
+                Some(id) => cards.push(new_card(wiki_card, id)),
+                None => problems.push(WikiCardError::NoFreeCardId { page: wiki_card.name, set_id: wiki_card.set_id }),
+            },
+        }
+    }
+    (cards, problems)
+}
+
+fn json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+fn find_by_name<'a>(cards: &'a [Card], name: &str) -> Option<&'a Card> {
+    cards.iter().find(|card| card.name.eq_ignore_ascii_case(name))
+}
+
+/// How each draft differs from the raw data, drafts that match it are left out.
+pub fn diff_cards(drafts: &[Card], existing: &[Card]) -> Vec<CardDiff> {
+    drafts.iter()
+        .filter_map(|draft| {
+            let existing_card = match find_by_name(existing, &draft.name) {
+                Some(existing_card) => existing_card,
+                None => return Some(CardDiff::Added { name: draft.name.clone(), id: draft.id }),
+            };
+            let changes: Vec<FieldChange> = [
+                ("id", existing_card.id.to_string(), draft.id.to_string()),
+                ("cost", existing_card.cost.to_string(), draft.cost.to_string()),
+                ("set_id", existing_card.set_id.to_string(), draft.set_id.to_string()),
+                ("editions", json(&existing_card.editions), json(&draft.editions)),
+                ("card_types", json(&existing_card.card_types), json(&draft.card_types)),
+                ("card_tags", json(&existing_card.card_tags), json(&draft.card_tags)),
+                ("texts", json(&existing_card.texts), json(&draft.texts)),
+                ("art_url", json(&existing_card.art_url), json(&draft.art_url)),
+            ].into_iter()
+                .filter(|(_, existing, draft)| existing != draft)
+                .map(|(field, existing, draft)| FieldChange { field, existing, draft })
+                .collect();
+            match changes.is_empty() {
+                true => None,
+                false => Some(CardDiff::Changed { name: draft.name.clone(), changes }),
+            }
+        })
+        .collect()
+}
+
+/// Reads every card page of a dump and compares the drafts with `existing`.
+pub fn draft_from_wiki_dump(xml: &str, existing: &[Card], sets: &[Set]) -> WikiDraft {
+    let pages = parse_wiki_dump(xml);
+    let mut problems = vec![];
+    let wiki_cards: Vec<WikiCard> = pages.iter()
+        .filter_map(|page| parse_wiki_card(page, sets).unwrap_or_else(|problem| {
+            problems.push(problem);
+            None
+        }))
+        .collect();
+    let (cards, draft_problems) = draft_cards(wiki_cards, existing);
+    problems.extend(draft_problems);
+    let diffs = diff_cards(&cards, existing);
+    WikiDraft { pages: pages.len(), cards, diffs, problems }
+}
+
+/// A card file for every set with a changed or new card, holding the set's cards from the raw data with the drafts
+/// swapped in, so a reviewed file can replace the one in `raw_data`. A card moved to another set is dropped from the
+/// file of the set it left. Returns the files written.
+pub fn write_draft_files(draft: &WikiDraft, existing: &[Card], sets: &[Set], folder: &Path) -> io::Result<Vec<PathBuf>> {
+    let changed_names: Vec<&str> = draft.diffs.iter()
+        .map(|diff| match diff {
+            CardDiff::Added { name, .. } | CardDiff::Changed { name, .. } => name.as_str(),
+        })
+        .collect();
+    let changed_sets: BTreeSet<u8> = changed_names.iter()
+        .flat_map(|name| [find_by_name(&draft.cards, name), find_by_name(existing, name)])
+        .flatten()
+        .map(|card| card.set_id)
+        .collect();
+
+    let mut written = vec![];
+    for set_id in changed_sets {
+        let file_name = match sets.iter().find(|set| set.id == set_id) {
+            Some(set) => set_card_file_name(set),
+            None => BASE_CARD_FILE_NAME.to_string(),
+        };
+        let mut cards: BTreeMap<u16, &Card> = existing.iter().filter(|card| card.set_id == set_id).map(|card| (card.id, card)).collect();
+        cards.retain(|_, card| find_by_name(&draft.cards, &card.name).is_none());
+        cards.extend(draft.cards.iter().filter(|card| card.set_id == set_id).map(|card| (card.id, card)));
+        let cards: Vec<&Card> = cards.into_values().collect();
+
+        let mut bytes = vec![];
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+        let mut serializer = serde_json::Serializer::with_formatter(&mut bytes, formatter);
+        cards.serialize(&mut serializer).map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+        fs::create_dir_all(folder)?;
+        let path = folder.join(file_name);
+        fs::write(&path, bytes)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs::{read, remove_dir_all}};
+
+    use crate::model::{card_tag::CardTag, card_validation::validate_card_data, data_loaders::{card_data_loader::get_all_card_vec, set_data_loader::get_set_vec}};
+    use super::*;
+
+    fn page(title: &str, infobox: &str) -> WikiPage {
+        WikiPage { title: title.to_string(), text: format!("{{{{Infobox Card\n{}\n}}}}\nMore about the card.", infobox) }
+    }
+
+    fn dump(pages: &[WikiPage]) -> String {
+        let pages: Vec<String> = pages.iter()
+            .map(|page| format!(
+                "<page><title>{}</title><revision><text>{}</text></revision></page>",
+                page.title,
+                page.text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")))
+            .collect();
+        format!("<mediawiki>{}</mediawiki>", pages.join("\n"))
+    }
+
+    fn courtyard_page(cost: &str) -> WikiPage {
+        page("Courtyard", &format!(
+            "|name = Courtyard\n|cost = {}\n|type1 = [[Action]]\n|set = Intrigue\n|image1 = Courtyard.jpg\n|image2 = Courtyard.jpg\n{}\n{}",
+            cost,
+            "|text1 = '''+3 Cards.'''<br>Put a card from your hand on top of your deck.",
+            "|text2 = '''+3 Cards.'''<br>Put a card from your hand onto your deck."
+        ))
+    }
+
+    fn apothecary_page() -> WikiPage {
+        page("Apothecary", "|cost = $2P\n|type1 = Action\n|set = Alchemy\n|text = +1 Card\n|image = Apothecary.jpg")
+    }
+
+    #[test]
+    fn test_parse_wiki_card() {
+        let wiki_card = parse_wiki_card(&apothecary_page(), &get_set_vec()).unwrap().unwrap();
+        assert_eq!(wiki_card, WikiCard {
+            name: "Apothecary".to_string(),
+            cost: Cost { coins: 2, potion: true, debt: 0, modifiers: vec![] },
+            set_id: 4,
+            editions: vec![1],
+            card_types: vec![CardType::Action],
+            texts: vec![CardText { edition: 1, text: "+1 Card".to_string(), errata: vec![], faq: vec![] }],
+            art_url: vec![wiki_image_url("Apothecary.jpg")],
+        });
+    }
+
+    #[test]
+    fn test_parse_wiki_card_per_edition_fields() {
+        let mill = page("Mill", "|cost = 4\n|type1 = Action\n|type2 = Victory\n|set = Intrigue (Second Edition)\n|editions = 2\n|text = 1st\n|text2 = +1 Card +1 Action\n|image2 = Mill.jpg");
+        let wiki_card = parse_wiki_card(&mill, &get_set_vec()).unwrap().unwrap();
+        assert_eq!(wiki_card.set_id, 2);
+        assert_eq!(wiki_card.editions, vec![2]);
+        assert_eq!(wiki_card.texts[0].text, "+1 Card +1 Action");
+        assert_eq!(wiki_card.art_url, vec![wiki_image_url("Mill.jpg")]);
+    }
+
+    #[test]
+    fn test_parse_wiki_card_not_a_card() {
+        let page = WikiPage { title: "Base".to_string(), text: "{{Infobox Set|name = Base}}".to_string() };
+        assert_eq!(parse_wiki_card(&page, &get_set_vec()), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_wiki_card_problems() {
+        let sets = get_set_vec();
+        let problem = |infobox: &str| parse_wiki_card(&page("Bad", infobox), &sets).unwrap_err();
+        assert_eq!(problem("|cost = 2\n|type1 = Action\n|image = Bad.jpg"), WikiCardError::MissingField { page: "Bad".to_string(), field: "set".to_string() });
+        assert_eq!(problem("|cost = 2\n|set = Promo\n|type1 = Action"), WikiCardError::UnknownSet { page: "Bad".to_string(), set_name: "Promo".to_string() });
+        assert_eq!(problem("|cost = 2\n|set = Base\n|type1 = Gadget"), WikiCardError::UnknownCardType { page: "Bad".to_string(), card_type: "Gadget".to_string() });
+        assert_eq!(problem("|cost = two\n|set = Base\n|type1 = Action"), WikiCardError::InvalidCost { page: "Bad".to_string(), cost: "two".to_string() });
+        assert_eq!(problem("|cost = 2\n|set = Base\n|image = Bad.jpg"), WikiCardError::MissingField { page: "Bad".to_string(), field: "type1".to_string() });
+        assert_eq!(problem("|cost = 2\n|set = Alchemy\n|type1 = Action\n|editions = 2\n|image = Bad.jpg"), WikiCardError::UnknownEdition { page: "Bad".to_string(), edition: "2".to_string() });
+        assert_eq!(problem("|cost = 2\n|set = Base\n|type1 = Action"), WikiCardError::MissingField { page: "Bad".to_string(), field: "image".to_string() });
+        assert_eq!(
+            WikiCardError::UnknownSet { page: "Bad".to_string(), set_name: "Promo".to_string() }.to_string(),
+            "Page Bad has unknown set Promo.");
+    }
+
+    #[test]
+    fn test_parse_cost() {
+        assert_eq!(parse_cost("4"), Some(Cost::from_coins(4)));
+        assert_eq!(parse_cost("{{Cost|3}}{{Potion}}"), Some(Cost { coins: 3, potion: true, debt: 0, modifiers: vec![] }));
+        assert_eq!(parse_cost("8D"), Some(Cost { coins: 0, potion: false, debt: 8, modifiers: vec![] }));
+        assert_eq!(parse_cost(""), None);
+        assert_eq!(parse_cost("$"), None);
+    }
+
+    #[test]
+    fn test_draft_existing_card_unchanged() {
+        let existing = get_all_card_vec();
+        let draft = draft_from_wiki_dump(&dump(&[courtyard_page("{{Cost|2}}")]), &existing, &get_set_vec());
+        let courtyard = existing.iter().find(|card| card.name == "Courtyard").unwrap();
+        assert_eq!(draft.pages, 1);
+        assert_eq!(draft.cards, vec![courtyard.clone()]);
+        assert_eq!(draft.diffs, vec![]);
+        assert_eq!(draft.problems, vec![]);
+    }
+
+    #[test]
+    fn test_draft_existing_card_changed() {
+        let existing = get_all_card_vec();
+        let courtyard = page("Courtyard", "|cost = 3\n|type1 = Action\n|set = Intrigue\n|text = '''+3 Cards'''\n|image = Courtyard.jpg");
+        let draft = draft_from_wiki_dump(&dump(&[courtyard]), &existing, &get_set_vec());
+        assert_eq!(draft.cards[0].id, 200);
+        assert_eq!(draft.cards[0].card_tags, vec![
+            CardTag::IsAction, CardTag::WillReplaceCard, CardTag::NetGainCards, CardTag::AddCardToTopOfDeck, CardTag::ControlsTopOfDeck, CardTag::Costs3
+        ]);
+        assert_eq!(draft.diffs, vec![CardDiff::Changed { name: "Courtyard".to_string(), changes: vec![
+            FieldChange { field: "cost", existing: "$2".to_string(), draft: "$3".to_string() },
+            FieldChange {
+                field: "card_tags",
+                existing: r#"["IsAction","Costs2","WillReplaceCard","NetGainCards","AddCardToTopOfDeck","ControlsTopOfDeck"]"#.to_string(),
+                draft: r#"["IsAction","WillReplaceCard","NetGainCards","AddCardToTopOfDeck","ControlsTopOfDeck","Costs3"]"#.to_string()
+            },
+            FieldChange {
+                field: "texts",
+                existing: concat!(
+                    r#"[{"edition":1,"text":"+3 Cards. Put a card from your hand on top of your deck.","errata":[],"faq":[]},"#,
+                    r#"{"edition":2,"text":"+3 Cards. Put a card from your hand onto your deck.","errata":[],"faq":[]}]"#
+                ).to_string(),
+                draft: r#"[{"edition":1,"text":"+3 Cards","errata":[],"faq":[]},{"edition":2,"text":"+3 Cards","errata":[],"faq":[]}]"#.to_string()
+            },
+        ] }]);
+        assert!(draft.diffs[0].to_string().starts_with("~ Courtyard\n    cost: $2 -> $3\n    card_tags: "));
+    }
+
+    #[test]
+    fn test_draft_new_cards() {
+        let existing = get_all_card_vec();
+        let sets = get_set_vec();
+        let vineyard = page("Vineyard", "|cost = $0P\n|type1 = Victory\n|set = Alchemy\n|text = Worth {{VP|1}} per 3 Action cards you have (round down).\n|image = Vineyard.jpg");
+        let broken = page("Broken", "|cost = 2\n|set = Nowhere\n|type1 = Action");
+        let draft = draft_from_wiki_dump(&dump(&[apothecary_page(), vineyard, broken]), &existing, &sets);
+
+        assert_eq!(draft.pages, 3);
+        assert_eq!(draft.diffs, vec![
+            CardDiff::Added { name: "Apothecary".to_string(), id: 400 },
+            CardDiff::Added { name: "Vineyard".to_string(), id: 401 },
+        ]);
+        assert_eq!(draft.problems, vec![WikiCardError::UnknownSet { page: "Broken".to_string(), set_name: "Nowhere".to_string() }]);
+        let vineyard = &draft.cards[1];
+        assert_eq!(vineyard.card_counts, [8, 12, 12, 12, 12]);
+        assert_eq!(vineyard.card_tags, vec![CardTag::IsVictory, CardTag::Costs0]);
+        assert_eq!(vineyard.texts[0].text, "Worth 1 VP per 3 Action cards you have (round down).");
+        assert_eq!(draft.diffs[0].to_string(), "+ Apothecary (id 400)");
+
+        let mut cards = existing.clone();
+        cards.extend(draft.cards.clone());
+        assert_eq!(validate_card_data(&cards, &sets), vec![]);
+    }
+
+    #[test]
+    fn test_draft_no_free_card_id() {
+        let mut existing = get_all_card_vec();
+        for id in set_card_id_range(4) {
+            let mut card = existing[0].clone();
+            card.id = id;
+            card.name = format!("Filler {}", id);
+            existing.push(card);
+        }
+        let draft = draft_from_wiki_dump(&dump(&[apothecary_page()]), &existing, &get_set_vec());
+        assert_eq!(draft.cards, vec![]);
+        assert_eq!(draft.problems, vec![WikiCardError::NoFreeCardId { page: "Apothecary".to_string(), set_id: 4 }]);
+    }
+
+    #[test]
+    fn test_draft_duplicate_card() {
+        let apothecary = apothecary_page();
+        let duplicate = page("apothecary", "|cost = $3P\n|type1 = Action\n|set = Alchemy\n|text = +2 Cards\n|image = Apothecary.jpg");
+        let draft = draft_from_wiki_dump(&dump(&[apothecary, duplicate]), &get_all_card_vec(), &get_set_vec());
+        assert_eq!(draft.diffs, vec![CardDiff::Added { name: "Apothecary".to_string(), id: 400 }]);
+        assert_eq!(draft.problems, vec![WikiCardError::DuplicateCard { page: "apothecary".to_string() }]);
+        assert_eq!(draft.problems[0].to_string(), "Page apothecary is a card an earlier page already drafted.");
+    }
+
+    #[test]
+    fn test_draft_card_moved_to_another_set() {
+        let existing = get_all_card_vec();
+        let sets = get_set_vec();
+        let courtyard = page("Courtyard", "|cost = 2\n|type1 = Action\n|set = Alchemy\n|text = +3 Cards\n|image = Courtyard.jpg");
+        let draft = draft_from_wiki_dump(&dump(&[courtyard]), &existing, &sets);
+        assert_eq!(draft.problems, vec![]);
+        assert_eq!((draft.cards[0].id, draft.cards[0].set_id), (400, 4));
+        let CardDiff::Changed { changes, .. } = &draft.diffs[0] else { panic!("Courtyard should be a changed card") };
+        assert_eq!(changes[0], FieldChange { field: "id", existing: "200".to_string(), draft: "400".to_string() });
+        assert_eq!(changes[1], FieldChange { field: "set_id", existing: "2".to_string(), draft: "4".to_string() });
+
+        let folder = temp_dir().join(format!("dominion_wiki_drafts_moved_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        let written = write_draft_files(&draft, &existing, &sets, &folder).unwrap();
+        assert_eq!(written, vec![folder.join("intrigue_cards.json"), folder.join("alchemy_cards.json")]);
+        let intrigue: Vec<Card> = serde_json::from_slice(&read(&written[0]).unwrap()).unwrap();
+        assert_eq!(intrigue.len(), existing.iter().filter(|card| card.set_id == 2).count() - 1);
+        assert!(intrigue.iter().all(|card| card.name != "Courtyard"));
+        let alchemy: Vec<Card> = serde_json::from_slice(&read(&written[1]).unwrap()).unwrap();
+        assert_eq!(alchemy, draft.cards);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_write_draft_files_basic_card() {
+        let folder = temp_dir().join(format!("dominion_wiki_drafts_basic_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        let existing = get_all_card_vec();
+        let sets = get_set_vec();
+        let copper = page("Copper", "|cost = 1\n|type1 = Treasure\n|set = Dominion\n|text = $1\n|image = Copper.jpg");
+        let draft = draft_from_wiki_dump(&dump(&[copper]), &existing, &sets);
+        assert_eq!(draft.cards[0].set_id, BASIC_CARD_SET_ID);
+
+        let written = write_draft_files(&draft, &existing, &sets, &folder).unwrap();
+        assert_eq!(written, vec![folder.join(BASE_CARD_FILE_NAME)]);
+        let cards: Vec<Card> = serde_json::from_slice(&read(&written[0]).unwrap()).unwrap();
+        assert_eq!(cards.len(), existing.iter().filter(|card| card.set_id == BASIC_CARD_SET_ID).count());
+        assert_eq!(cards.iter().find(|card| card.name == "Copper").unwrap().cost, Cost::from_coins(1));
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_write_draft_files() {
+        let folder = temp_dir().join(format!("dominion_wiki_drafts_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        let existing = get_all_card_vec();
+        let sets = get_set_vec();
+        let draft = draft_from_wiki_dump(&dump(&[courtyard_page("2"), apothecary_page()]), &existing, &sets);
+
+        let written = write_draft_files(&draft, &existing, &sets, &folder).unwrap();
+        assert_eq!(written, vec![folder.join("alchemy_cards.json")]);
+        let cards: Vec<Card> = serde_json::from_slice(&read(&written[0]).unwrap()).unwrap();
+        assert_eq!(cards, draft.cards[1..].to_vec());
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_write_draft_files_keeps_other_cards() {
+        let folder = temp_dir().join(format!("dominion_wiki_drafts_changed_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        let existing = get_all_card_vec();
+        let sets = get_set_vec();
+        let draft = draft_from_wiki_dump(&dump(&[courtyard_page("3")]), &existing, &sets);
+
+        let written = write_draft_files(&draft, &existing, &sets, &folder).unwrap();
+        assert_eq!(written, vec![folder.join("intrigue_cards.json")]);
+        let cards: Vec<Card> = serde_json::from_slice(&read(&written[0]).unwrap()).unwrap();
+        assert_eq!(cards.len(), existing.iter().filter(|card| card.set_id == 2).count());
+        assert_eq!(cards.iter().find(|card| card.name == "Courtyard").unwrap().cost, Cost::from_coins(3));
+        assert_eq!(cards.iter().find(|card| card.name == "Nobles"), existing.iter().find(|card| card.name == "Nobles"));
+        let _ = remove_dir_all(folder);
+    }
+}
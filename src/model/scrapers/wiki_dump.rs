@@ -0,0 +1,227 @@
+use std::{collections::{BTreeMap, HashMap}, sync::{LazyLock, Mutex}};
+
+use md5::{Digest, Md5};
+use regex::Regex;
+
+pub const WIKI_IMAGE_URL: &str = "http://wiki.dominionstrategy.com/images";
+
+// compiled once, a full wiki export runs them over thousands of pages
+static XML_ENTITY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|lt|gt|amp|quot|apos);").unwrap());
+static XML_PAGE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<page>(.*?)</page>").unwrap());
+static XML_TITLE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<title>(.*?)</title>").unwrap());
+static XML_TEXT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<text[^>]*>(.*?)</text>").unwrap());
+static WIKI_COMMENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<!--.*?-->").unwrap());
+static WIKI_TEMPLATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{\{([^{}]*)\}\}").unwrap());
+static WIKI_LINK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[\[(?:[^\[\]|]*\|)?([^\[\]]*)\]\]").unwrap());
+static HTML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)<br\s*/?>|</?[a-z][^>]*>").unwrap());
+// the start of each template name asked for, keyed by the name
+static TEMPLATE_STARTS: LazyLock<Mutex<HashMap<String, Regex>>> = LazyLock::new(Default::default);
+
+/// One page of a MediaWiki XML export, `text` is the page's wiki markup.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WikiPage {
+    pub title: String,
+    pub text: String,
+}
+
+fn unescape_xml(text: &str) -> String {
+    XML_ENTITY.replace_all(text, |captures: &regex::Captures| {
+        let name = &captures[1];
+        let character = match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if name.starts_with("#x") => u32::from_str_radix(&name[2..], 16).ok().and_then(char::from_u32),
+            _ => name[1..].parse().ok().and_then(char::from_u32),
+        };
+        character.map(String::from).unwrap_or_else(|| captures[0].to_string())
+    }).into_owned()
+}
+
+/// The pages of a dump saved from the wiki's Special:Export page, only the latest revision of each is read.
+pub fn parse_wiki_dump(xml: &str) -> Vec<WikiPage> {
+    XML_PAGE.captures_iter(xml)
+        .filter_map(|page| {
+            let title = XML_TITLE.captures(&page[1])?;
+            let text = XML_TEXT.captures_iter(&page[1]).last()
+                .map(|text| unescape_xml(&text[1]))
+                .unwrap_or_default();
+            Some(WikiPage { title: unescape_xml(title[1].trim()), text })
+        })
+        .collect()
+}
+
+/// Splits on `separator` where it is not inside a nested `{{template}}` or `[[link]]`.
+fn split_top_level(text: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut depth = 0;
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        let doubled = characters.peek() == Some(&character);
+        match character {
+            '{' | '[' if doubled => {
+                depth += 1;
+                parts.last_mut().unwrap().push(character);
+                parts.last_mut().unwrap().push(characters.next().unwrap());
+            },
+            '}' | ']' if doubled && depth > 0 => {
+                depth -= 1;
+                parts.last_mut().unwrap().push(character);
+                parts.last_mut().unwrap().push(characters.next().unwrap());
+            },
+            _ if character == separator && depth == 0 => parts.push(String::new()),
+            _ => parts.last_mut().unwrap().push(character),
+        }
+    }
+    parts
+}
+
+/// The inside of the first `{{name ...}}` template in `text`, without its closing braces.
+fn template_body<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let body_start = TEMPLATE_STARTS.lock().unwrap()
+        .entry(name.to_string())
+        .or_insert_with(|| Regex::new(&format!(r"(?i)\{{\{{\s*{}\s*", regex::escape(name).replace(' ', "[ _]"))).unwrap())
+        .find(text)?
+        .end();
+    let mut depth = 1;
+    let bytes = text.as_bytes();
+    let mut index = body_start;
+    while index + 1 < bytes.len() {
+        match &bytes[index..index + 2] {
+            b"{{" => { depth += 1; index += 2; },
+            b"}}" => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[body_start..index]);
+                }
+                index += 2;
+            },
+            _ => index += 1,
+        }
+    }
+    None
+}
+
+/// The named fields of the first `{{name ...}}` template on a page, keys are lower case.
+pub fn template_fields(text: &str, name: &str) -> Option<BTreeMap<String, String>> {
+    let text = WIKI_COMMENT.replace_all(text, "");
+    let body = template_body(&text, name)?;
+    Some(split_top_level(body, '|').into_iter()
+        .skip(1)
+        .filter_map(|field| {
+            let (key, value) = field.split_once('=')?;
+            Some((key.trim().to_lowercase(), value.trim().to_string()))
+        })
+        .collect())
+}
+
+/// What a template looks like printed on a card, `{{Cost|2}}` is `$2` and `{{VP|1}}` is `1 VP`.
+fn render_template(template: &str) -> String {
+    let arguments: Vec<String> = split_top_level(template, '|').into_iter().map(|argument| argument.trim().to_string()).collect();
+    let name = arguments[0].to_lowercase();
+    let argument = arguments.get(1).map(String::as_str).unwrap_or("");
+    match name.as_str() {
+        "cost" | "coin" | "coins" => format!("${}", argument),
+        "vp" if argument.is_empty() => "VP".to_string(),
+        "vp" => format!("{} VP", argument),
+        "potion" | "p" => "P".to_string(),
+        "debt" | "d" => format!("{}D", argument),
+        _ => arguments.last().cloned().unwrap_or_default(),
+    }
+}
+
+/// Turns wiki markup into the plain text printed on a card, on one line.
+pub fn wiki_markup_to_text(markup: &str) -> String {
+    let mut text = markup.to_string();
+    // innermost templates first so arguments are already plain text
+    while WIKI_TEMPLATE.is_match(&text) {
+        text = WIKI_TEMPLATE.replace_all(&text, |captures: &regex::Captures| render_template(&captures[1])).into_owned();
+    }
+    text = WIKI_LINK.replace_all(&text, "$1").into_owned();
+    text = HTML_TAG.replace_all(&text, " ").into_owned();
+    text = text.replace("'''", "").replace("''", "");
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Where the wiki keeps an uploaded file, MediaWiki files images under the start of the md5 of their name.
+pub fn wiki_image_url(file_name: &str) -> String {
+    let file_name = file_name.trim().trim_start_matches("File:").replace(' ', "_");
+    let mut characters = file_name.chars();
+    let file_name = match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect::<String>(),
+        None => file_name,
+    };
+    let hash: String = Md5::digest(file_name.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}/{}/{}/{}", WIKI_IMAGE_URL, &hash[..1], &hash[..2], file_name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DUMP: &str = r#"<mediawiki xmlns="http://www.mediawiki.org/xml/export-0.10/">
+  <siteinfo><sitename>Dominion Strategy Wiki</sitename></siteinfo>
+  <page>
+    <title>Moat</title>
+    <ns>0</ns>
+    <revision>
+      <text bytes="10" xml:space="preserve">old text</text>
+    </revision>
+    <revision>
+      <text bytes="99" xml:space="preserve">{{Infobox Card
+ |name = Moat
+ |cost = 2
+ |text = '''+2 Cards'''&lt;br/&gt;When another player plays an [[Attack]] card &amp; so on
+}}</text>
+    </revision>
+  </page>
+  <page>
+    <title>Rats &amp; Mice</title>
+    <revision><text /></revision>
+  </page>
+</mediawiki>"#;
+
+    #[test]
+    fn test_parse_wiki_dump() {
+        let pages = parse_wiki_dump(DUMP);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].title, "Moat");
+        assert!(pages[0].text.starts_with("{{Infobox Card"));
+        assert!(pages[0].text.contains("<br/>When another player plays an [[Attack]] card & so on"));
+        assert_eq!(pages[1], WikiPage { title: "Rats & Mice".to_string(), text: String::new() });
+    }
+
+    #[test]
+    fn test_unescape_xml() {
+        assert_eq!(unescape_xml("&lt;b&gt; &#39;a&#x27; &amp;amp; &unknown;"), "<b> 'a' &amp; &unknown;");
+    }
+
+    #[test]
+    fn test_template_fields() {
+        let text = "Intro {{Other|x=1}}\n{{Infobox_card\n|name = Mine <!-- 1st edition wording below -->\n|text = Gain a {{Card|Treasure|Treasure card}} costing up to {{Cost|3}} more.\n|image = Mine.jpg\n}} trailing";
+        let fields = template_fields(text, "Infobox Card").unwrap();
+        assert_eq!(fields["name"], "Mine");
+        assert_eq!(fields["text"], "Gain a {{Card|Treasure|Treasure card}} costing up to {{Cost|3}} more.");
+        assert_eq!(fields["image"], "Mine.jpg");
+        assert!(!fields.contains_key("x"));
+        assert_eq!(template_fields("No infobox here", "Infobox Card"), None);
+        assert_eq!(template_fields("{{Infobox Card|name = Unclosed", "Infobox Card"), None);
+    }
+
+    #[test]
+    fn test_wiki_markup_to_text() {
+        assert_eq!(wiki_markup_to_text("'''+1 Card'''<br>'''+1 Action'''<br/>'''+{{Cost|2}}'''"), "+1 Card +1 Action +$2");
+        assert_eq!(wiki_markup_to_text("Worth {{VP|1}} per 10 cards you have ([[Rounding|round down]])."), "Worth 1 VP per 10 cards you have (round down).");
+        assert_eq!(wiki_markup_to_text("Gain a {{Card|Gold}}.\n\nCosts {{Cost|{{Potion}}}}."), "Gain a Gold. Costs $P.");
+        assert_eq!(wiki_markup_to_text("Take {{Debt|8}}."), "Take 8D.");
+    }
+
+    #[test]
+    fn test_wiki_image_url() {
+        assert_eq!(wiki_image_url("Moat.jpg"), "http://wiki.dominionstrategy.com/images/f/fe/Moat.jpg");
+        assert_eq!(wiki_image_url("File:courtyard.jpg"), "http://wiki.dominionstrategy.com/images/3/30/Courtyard.jpg");
+        assert_eq!(wiki_image_url("Great Hall.jpg"), wiki_image_url("Great_Hall.jpg"));
+    }
+}
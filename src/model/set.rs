@@ -44,19 +44,24 @@ impl Set {
 }
 
 pub trait SetFilePaths {
+    fn get_folder_name(&self, edition: u8) -> String;
     fn get_cover_art_path(&self, edition: u8) -> String;
     fn get_rule_book_path(&self, edition: u8) -> String;
     fn get_icon_path(&self, edition: u8) -> String;
 }
 
 impl SetFilePaths for Set {
-    fn get_cover_art_path(&self, edition: u8) -> String {
+    fn get_folder_name(&self, edition: u8) -> String {
         if !self.editions.contains(&edition){ panic!("Unkowen edition."); }
         let regex = Regex::new(r"[\s<>:;',?*|\\]").unwrap();
-        let safe_set_name = regex.replace_all(
+        regex.replace_all(
             format!("{0}_{1}", self.name, edition).as_str(), 
             "-"
-        ).as_ref().to_string();
+        ).as_ref().to_string()
+    }
+
+    fn get_cover_art_path(&self, edition: u8) -> String {
+        let safe_set_name = self.get_folder_name(edition);
         let target_folder = canonicalize("src/model/scraped_data/").unwrap();
         let mut path = PathBuf::new();
         path.push(target_folder);
@@ -67,12 +72,7 @@ impl SetFilePaths for Set {
     }
 
     fn get_rule_book_path(&self, edition: u8) -> String{
-        if !self.editions.contains(&edition){ panic!("Unkowen edition."); }
-        let regex = Regex::new(r"[\s<>:;',?*|\\]").unwrap();
-        let safe_set_name = regex.replace_all(
-            format!("{0}_{1}", self.name, edition).as_str(), 
-            "-"
-        ).as_ref().to_string();
+        let safe_set_name = self.get_folder_name(edition);
         let target_folder = canonicalize("src/model/scraped_data/").unwrap();
         let mut path = PathBuf::new();
         path.push(target_folder);
@@ -83,12 +83,7 @@ impl SetFilePaths for Set {
     }
 
     fn get_icon_path(&self, edition: u8) -> String{
-        if !self.editions.contains(&edition){ panic!("Unkowen edition."); }
-        let regex = Regex::new(r"[\s<>:;',?*|\\]").unwrap();
-        let safe_set_name = regex.replace_all(
-            format!("{0}_{1}", self.name, edition).as_str(), 
-            "-"
-        ).as_ref().to_string();
+        let safe_set_name = self.get_folder_name(edition);
         let target_folder = canonicalize("src/model/scraped_data/").unwrap();
         let mut path = PathBuf::new();
         path.push(target_folder);
@@ -444,6 +439,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_get_folder_name() {
+        let set = Set::new( 
+            1, 
+            "test name'one".to_string(), 
+            vec![1, 2], 
+            vec!["www.cover_art_url.com".to_owned(), "www.cover_art_url.com".to_owned()], 
+            vec!["www.rule_book_url.com".to_owned(), "www.rule_book_url.com".to_owned()], 
+            vec!["www.icon_url.com".to_owned(), "www.icon_url.com".to_owned()]
+        );
+        assert_eq!(set.get_folder_name(2), "test-name-one_2");
+    }
+
     #[test]
     fn test_get_cover_art_path() {
         let set = Set::new( 
@@ -1,24 +1,149 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Deref, sync::{Arc, RwLock}};
 
-use crate::model::{card::Card, data_loaders::card_data_loader::{get_all_card_id_map, get_all_card_name_map}};
+use rocket::{http::Status, request::{FromRequest, Outcome, Request}};
+
+use crate::model::{
+    card::Card,
+    card_tag::CardTag,
+    card_type::CardType,
+    data_loaders::{
+        card_data_loader::{card_id_map, card_name_map, get_all_card_vec, load_all_card_vec, load_set_card_vec},
+        data_dir::{DataDir, DataLoadError},
+        landscape_data_loader::{get_landscape_vec, load_landscape_vec}
+    },
+    landscape::Landscape,
+    set::Set
+};
 
 pub struct CardData {
     pub id_card_map: HashMap<u16, Card>,
-    pub name_card_map: HashMap<String, Card>
+    pub name_card_map: HashMap<String, Card>,
+    pub card_type_index: HashMap<CardType, Vec<u16>>,
+    pub card_tag_index: HashMap<CardTag, Vec<u16>>,
+    pub set_index: HashMap<u8, Vec<u16>>,
+    pub id_landscape_map: HashMap<u16, Landscape>,
 }
 
 pub fn build_card_data() -> CardData {
+    build_card_data_from_cards(get_all_card_vec(), get_landscape_vec())
+}
+
+/// Loads the cards and landscapes, the error lists the malformed records of both.
+pub fn load_card_data(data_dir: &DataDir) -> Result<CardData, DataLoadError> {
+    build_loaded_card_data(load_all_card_vec(data_dir), load_landscape_vec(data_dir))
+}
+
+/// Loads the cards of `sets` and the landscapes, so the card data matches set data built from the same sets.
+pub fn load_card_data_for_sets(data_dir: &DataDir, sets: &[Set]) -> Result<CardData, DataLoadError> {
+    build_loaded_card_data(load_set_card_vec(data_dir, sets), load_landscape_vec(data_dir))
+}
+
+fn build_loaded_card_data(
+    cards: Result<Vec<Card>, DataLoadError>,
+    landscapes: Result<Vec<Landscape>, DataLoadError>
+) -> Result<CardData, DataLoadError> {
+    match (cards, landscapes) {
+        (Ok(cards), Ok(landscapes)) => Ok(build_card_data_from_cards(cards, landscapes)),
+        (cards, landscapes) => Err(DataLoadError {
+            malformed_records: [cards.err(), landscapes.err()].into_iter()
+                .flatten()
+                .flat_map(|error| error.malformed_records)
+                .collect()
+        }),
+    }
+}
+
+pub fn build_card_data_from_cards(mut cards: Vec<Card>, landscapes: Vec<Landscape>) -> CardData {
+    let mut card_type_index: HashMap<CardType, Vec<u16>> = HashMap::new();
+    let mut card_tag_index: HashMap<CardTag, Vec<u16>> = HashMap::new();
+    let mut set_index: HashMap<u8, Vec<u16>> = HashMap::new();
+
+    cards.sort_by_key(|card| card.id);
+    for card in cards.iter() {
+        for card_type in card.card_types.iter() {
+            card_type_index.entry(*card_type).or_default().push(card.id);
+        }
+        for card_tag in card.card_tags.iter() {
+            card_tag_index.entry(*card_tag).or_default().push(card.id);
+        }
+        set_index.entry(card.set_id).or_default().push(card.id);
+    }
+
     CardData { 
-        id_card_map: get_all_card_id_map(),
-        name_card_map: get_all_card_name_map()
+        id_card_map: card_id_map(cards.clone()),
+        name_card_map: card_name_map(cards),
+        card_type_index,
+        card_tag_index,
+        set_index,
+        id_landscape_map: landscapes.into_iter().map(|landscape| (landscape.id, landscape)).collect()
+    }
+}
+
+/// The managed card data, it can be reloaded from its data directory while the server is running.
+pub struct CardDataState {
+    pub data_dir: DataDir,
+    card_data: RwLock<Arc<CardData>>,
+}
+
+impl CardDataState {
+    pub fn new(data_dir: DataDir, card_data: CardData) -> Self {
+        Self { data_dir, card_data: RwLock::new(Arc::new(card_data)) }
+    }
+
+    pub fn current(&self) -> Arc<CardData> {
+        self.card_data.read().unwrap().clone()
+    }
+
+    /// Only replaces the card data once the card files of `sets` and the landscapes have loaded.
+    pub fn reload(&self, sets: &[Set]) -> Result<Arc<CardData>, DataLoadError> {
+        let card_data = Arc::new(load_card_data_for_sets(&self.data_dir, sets)?);
+        *self.card_data.write().unwrap() = card_data.clone();
+        Ok(card_data)
+    }
+}
+
+/// Panics with every malformed record when the data directory can not be loaded.
+pub fn build_card_data_state(data_dir: DataDir) -> CardDataState {
+    let card_data = load_card_data(&data_dir)
+        .unwrap_or_else(|error| panic!("Could not load the card data. {}", error));
+    CardDataState::new(data_dir, card_data)
+}
+
+/// The card data a request was started with, a reload part way through a request is not seen by it.
+pub struct CardDataSnapshot(pub Arc<CardData>);
+
+impl Deref for CardDataSnapshot {
+    type Target = CardData;
+
+    fn deref(&self) -> &CardData {
+        &self.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for CardDataSnapshot {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, ()> {
+        match request.rocket().state::<CardDataState>() {
+            Some(state) => Outcome::Success(CardDataSnapshot(state.current())),
+            None => Outcome::Error((Status::InternalServerError, ())),
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::model::data_loaders::card_data_loader::get_all_card_vec;
+    use std::{env::temp_dir, fs::{create_dir_all, remove_dir_all, write}};
+
+    use rocket::serde::json::serde_json;
 
-    use super::build_card_data;
+    use super::*;
+    use crate::model::data_loaders::{
+        card_data_loader::{BASE_CARD_FILE_NAME, get_base_card_vec},
+        landscape_data_loader::LANDSCAPE_FILE_NAME,
+        set_data_loader::get_set_vec
+    };
 
     #[test]
     fn test_card_id_map() {
@@ -37,4 +162,97 @@ mod test {
             assert_eq!(card, &card_data.name_card_map[&card.name.to_ascii_lowercase()]);
         }
     }
+
+    #[test]
+    fn test_indexes_hold_every_card() {
+        let card_data = build_card_data();
+        for card in get_all_card_vec().iter() {
+            assert!(card_data.set_index[&card.set_id].contains(&card.id));
+            for card_type in card.card_types.iter() {
+                assert!(card_data.card_type_index[card_type].contains(&card.id));
+            }
+            for card_tag in card.card_tags.iter() {
+                assert!(card_data.card_tag_index[card_tag].contains(&card.id));
+            }
+        }
+    }
+
+    #[test]
+    fn test_indexes_are_sorted() {
+        let card_data = build_card_data();
+        let ids = &card_data.card_type_index[&CardType::Attack];
+        let mut sorted = ids.clone();
+        sorted.sort_unstable();
+        assert_eq!(ids, &sorted);
+        assert_eq!(
+            card_data.card_tag_index[&CardTag::CurseAttack],
+            ["Witch", "Replace", "Torturer"].map(|name| card_data.name_card_map[name].id)
+        );
+        assert_eq!(card_data.set_index[&0], vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_landscape_map() {
+        let card_data = build_card_data();
+        for landscape in get_landscape_vec().iter() {
+            assert_eq!(landscape, &card_data.id_landscape_map[&landscape.id]);
+        }
+    }
+
+    #[test]
+    fn test_load_card_data_lists_malformed_landscapes() {
+        let folder = temp_dir().join(format!("dominion_card_data_landscapes_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        write(folder.join(BASE_CARD_FILE_NAME), "[{}]").unwrap();
+        write(folder.join(LANDSCAPE_FILE_NAME), "[{}, {}]").unwrap();
+
+        let error = load_card_data(&DataDir::new(&folder)).err().unwrap();
+        let file_names: Vec<&str> = error.malformed_records.iter().map(|record| record.file_name.as_str()).collect();
+        assert_eq!(file_names, vec![BASE_CARD_FILE_NAME, LANDSCAPE_FILE_NAME, LANDSCAPE_FILE_NAME]);
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_card_data_state_reload() {
+        let folder = temp_dir().join(format!("dominion_card_data_reload_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        let state = build_card_data_state(DataDir::new(&folder));
+        let before = state.current();
+        assert_eq!(before.id_card_map[&0].name, "Copper");
+
+        let mut cards = get_base_card_vec();
+        cards[0].name = "Fixed Copper".to_string();
+        write(folder.join(BASE_CARD_FILE_NAME), serde_json::to_string(&cards).unwrap()).unwrap();
+        state.reload(&get_set_vec()).unwrap();
+
+        assert_eq!(state.current().id_card_map[&0].name, "Fixed Copper");
+        assert_eq!(state.current().name_card_map["fixed copper"].id, 0);
+        assert_eq!(before.id_card_map[&0].name, "Copper");
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    fn test_card_data_state_failed_reload_keeps_card_data() {
+        let folder = temp_dir().join(format!("dominion_card_data_failed_reload_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        let state = build_card_data_state(DataDir::new(&folder));
+
+        write(folder.join(BASE_CARD_FILE_NAME), "[{}]").unwrap();
+        assert_eq!(state.reload(&get_set_vec()).err().unwrap().malformed_records.len(), 1);
+        assert_eq!(state.current().id_card_map.len(), get_all_card_vec().len());
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    #[should_panic = "Could not load the card data. 1 malformed records."]
+    fn test_build_card_data_state_malformed_data() {
+        let folder = temp_dir().join(format!("dominion_card_data_malformed_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        write(folder.join(BASE_CARD_FILE_NAME), "[{}]").unwrap();
+        let _ = build_card_data_state(DataDir::new(&folder));
+    }
 }
@@ -0,0 +1,83 @@
+use std::sync::Mutex;
+
+use crate::model::league::{league_record::League, league_store::LeagueStore};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+pub struct LeagueData {
+    pub store: LeagueStore,
+    pub league: Mutex<League>,
+}
+
+pub fn build_league_data(store: LeagueStore) -> LeagueData {
+    let league = store.load().expect("Could not load the league store.");
+    LeagueData {
+        store,
+        league: Mutex::new(league)
+    }
+}
+
+impl LeagueData {
+    /// Applies `change` to a copy of the league and only keeps it once the copy has been saved.
+    pub fn update<T>(&self, change: impl FnOnce(&mut League) -> T) -> Result<T> {
+        let mut league = self.league.lock().unwrap();
+        let mut updated = league.clone();
+        let result = change(&mut updated);
+        self.store.save(&updated)?;
+        *league = updated;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs::remove_dir_all};
+
+    use super::*;
+
+    fn test_store(name: &str) -> LeagueStore {
+        let folder = temp_dir().join(format!("dominion_league_data_{}_{}", name, std::process::id()));
+        let _ = remove_dir_all(&folder);
+        LeagueStore::new(folder.join("league.json"))
+    }
+
+    #[test]
+    fn test_build_league_data_empty_store() {
+        let league_data = build_league_data(test_store("empty"));
+        assert_eq!(*league_data.league.lock().unwrap(), League::default());
+    }
+
+    #[test]
+    fn test_update_saves_league() {
+        let store = test_store("update");
+        let league_data = build_league_data(store.clone());
+        let season_id = league_data.update(|league| league.add_season("Spring")).unwrap();
+
+        assert_eq!(season_id, 1);
+        assert_eq!(league_data.league.lock().unwrap().seasons.len(), 1);
+        assert_eq!(store.load().unwrap().seasons.len(), 1);
+        let _ = remove_dir_all(store.path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_update_failed_save_keeps_league() {
+        let folder = test_store("failed_update").path.parent().unwrap().to_path_buf();
+        std::fs::create_dir_all(&folder).unwrap();
+        // a directory where the league file should be makes every save fail
+        std::fs::create_dir_all(folder.join("league.json.tmp")).unwrap();
+        let league_data = LeagueData { store: LeagueStore::new(folder.join("league.json")), league: Mutex::new(League::default()) };
+
+        assert!(league_data.update(|league| league.add_season("Spring")).is_err());
+        assert!(league_data.league.lock().unwrap().seasons.is_empty());
+        let _ = remove_dir_all(folder);
+    }
+
+    #[test]
+    #[should_panic = "Could not load the league store."]
+    fn test_build_league_data_malformed_store() {
+        let store = test_store("malformed");
+        std::fs::create_dir_all(store.path.parent().unwrap()).unwrap();
+        std::fs::write(&store.path, "not a league").unwrap();
+        let _ = build_league_data(store);
+    }
+}
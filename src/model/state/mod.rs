@@ -1 +1,4 @@
 pub mod card_data;
+pub mod league_data;
+pub mod scraped_manifest;
+pub mod set_data;
@@ -0,0 +1,114 @@
+use std::{io, ops::Deref, path::{Path, PathBuf}, sync::{Arc, RwLock}};
+
+use rocket::{http::Status, request::{FromRequest, Outcome, Request}};
+
+use crate::model::scrapers::scrape_engine::{MANIFEST_FILE_NAME, Manifest};
+
+/// The scrape manifest with the path it was read from, its keys are relative to that path.
+#[derive(Debug, Default)]
+pub struct ScrapedManifest {
+    pub manifest_path: PathBuf,
+    pub manifest: Manifest,
+}
+
+impl ScrapedManifest {
+    pub fn mime_type(&self, path: &Path) -> Option<&str> {
+        self.manifest.mime_type(&self.manifest_path, path)
+    }
+}
+
+/// The managed scrape manifest, read once at launch and again on every admin reload.
+pub struct ScrapedManifestState {
+    manifest_path: PathBuf,
+    scraped_manifest: RwLock<Arc<ScrapedManifest>>,
+}
+
+impl ScrapedManifestState {
+    pub fn new(scraped_manifest: ScrapedManifest) -> Self {
+        Self {
+            manifest_path: scraped_manifest.manifest_path.clone(),
+            scraped_manifest: RwLock::new(Arc::new(scraped_manifest))
+        }
+    }
+
+    pub fn current(&self) -> Arc<ScrapedManifest> {
+        self.scraped_manifest.read().unwrap().clone()
+    }
+
+    /// Reads the manifest a scrape left behind without replacing the one being served.
+    pub fn load(&self) -> io::Result<ScrapedManifest> {
+        let manifest = Manifest::load(&self.manifest_path)?;
+        Ok(ScrapedManifest { manifest_path: self.manifest_path.clone(), manifest })
+    }
+
+    pub fn replace(&self, scraped_manifest: ScrapedManifest) -> Arc<ScrapedManifest> {
+        let scraped_manifest = Arc::new(scraped_manifest);
+        *self.scraped_manifest.write().unwrap() = scraped_manifest.clone();
+        scraped_manifest
+    }
+}
+
+/// A missing or malformed manifest serves every scraped file by its extension.
+pub fn build_scraped_manifest_state(output_dir: &Path) -> ScrapedManifestState {
+    let manifest_path = output_dir.join(MANIFEST_FILE_NAME);
+    let manifest = Manifest::load(&manifest_path).unwrap_or_default();
+    ScrapedManifestState::new(ScrapedManifest { manifest_path, manifest })
+}
+
+/// The scrape manifest a request was started with.
+pub struct ScrapedManifestSnapshot(pub Arc<ScrapedManifest>);
+
+impl Deref for ScrapedManifestSnapshot {
+    type Target = ScrapedManifest;
+
+    fn deref(&self) -> &ScrapedManifest {
+        &self.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ScrapedManifestSnapshot {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, ()> {
+        match request.rocket().state::<ScrapedManifestState>() {
+            Some(state) => Outcome::Success(ScrapedManifestSnapshot(state.current())),
+            None => Outcome::Error((Status::InternalServerError, ())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env::temp_dir, fs::{create_dir_all, remove_dir_all, write}};
+
+    use crate::model::scrapers::scrape_engine::ManifestEntry;
+    use super::*;
+
+    #[test]
+    fn test_scraped_manifest_state_replace() {
+        let folder = temp_dir().join(format!("dominion_scraped_manifest_{}", std::process::id()));
+        let _ = remove_dir_all(&folder);
+        create_dir_all(&folder).unwrap();
+        let state = build_scraped_manifest_state(&folder);
+        assert!(state.current().manifest.files.is_empty());
+
+        let mut manifest = Manifest::default();
+        manifest.files.insert("cards/Chapel_1.jpeg".to_string(), ManifestEntry {
+            url: "http://a".to_string(),
+            sha256: "00".to_string(),
+            mime_type: Some("image/png".to_string())
+        });
+        manifest.save(&folder.join(MANIFEST_FILE_NAME)).unwrap();
+        // the manifest is not read again until it is reloaded
+        assert_eq!(state.current().mime_type(&folder.join("cards/Chapel_1.jpeg")), None);
+
+        state.replace(state.load().unwrap());
+        assert_eq!(state.current().mime_type(&folder.join("cards/Chapel_1.jpeg")), Some("image/png"));
+
+        write(folder.join(MANIFEST_FILE_NAME), "{").unwrap();
+        assert!(state.load().is_err());
+        assert_eq!(state.current().mime_type(&folder.join("cards/Chapel_1.jpeg")), Some("image/png"));
+        let _ = remove_dir_all(folder);
+    }
+}
@@ -0,0 +1,111 @@
+use std::{collections::HashMap, ops::Deref, sync::{Arc, RwLock}};
+
+use rocket::{http::Status, request::{FromRequest, Outcome, Request}};
+
+use crate::model::{
+    data_loaders::{data_dir::{DataDir, DataLoadError}, set_data_loader::{get_set_vec, load_set_vec}},
+    set::Set
+};
+
+pub struct SetData {
+    pub sets: Vec<Set>,
+    pub id_set_map: HashMap<u8, Set>,
+}
+
+pub fn build_set_data() -> SetData {
+    build_set_data_from_sets(get_set_vec())
+}
+
+pub fn load_set_data(data_dir: &DataDir) -> Result<SetData, DataLoadError> {
+    Ok(build_set_data_from_sets(load_set_vec(data_dir)?))
+}
+
+pub fn build_set_data_from_sets(sets: Vec<Set>) -> SetData {
+    SetData {
+        id_set_map: sets.iter().map(|set| (set.id, set.clone())).collect(),
+        sets
+    }
+}
+
+/// The managed set data, it is reloaded with the card data from the same data directory.
+pub struct SetDataState {
+    set_data: RwLock<Arc<SetData>>,
+}
+
+impl SetDataState {
+    pub fn new(set_data: SetData) -> Self {
+        Self { set_data: RwLock::new(Arc::new(set_data)) }
+    }
+
+    pub fn current(&self) -> Arc<SetData> {
+        self.set_data.read().unwrap().clone()
+    }
+
+    pub fn replace(&self, set_data: SetData) -> Arc<SetData> {
+        let set_data = Arc::new(set_data);
+        *self.set_data.write().unwrap() = set_data.clone();
+        set_data
+    }
+}
+
+/// Panics with every malformed record when the set file can not be loaded.
+pub fn build_set_data_state(data_dir: &DataDir) -> SetDataState {
+    let set_data = load_set_data(data_dir)
+        .unwrap_or_else(|error| panic!("Could not load the set data. {}", error));
+    SetDataState::new(set_data)
+}
+
+/// The set data a request was started with.
+pub struct SetDataSnapshot(pub Arc<SetData>);
+
+impl Deref for SetDataSnapshot {
+    type Target = SetData;
+
+    fn deref(&self) -> &SetData {
+        &self.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for SetDataSnapshot {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, ()> {
+        match request.rocket().state::<SetDataState>() {
+            Some(state) => Outcome::Success(SetDataSnapshot(state.current())),
+            None => Outcome::Error((Status::InternalServerError, ())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::data_loaders::set_data_loader::get_set_vec;
+
+    use super::*;
+
+    #[test]
+    fn test_set_id_map() {
+        let set_data = build_set_data();
+        for set in get_set_vec().iter() {
+            assert_eq!(set, &set_data.id_set_map[&set.id]);
+        }
+    }
+
+    #[test]
+    fn test_sets_in_file_order() {
+        assert_eq!(build_set_data().sets, get_set_vec());
+    }
+
+    #[test]
+    fn test_set_data_state_replace() {
+        let state = SetDataState::new(build_set_data());
+        let before = state.current();
+        let mut sets = get_set_vec();
+        sets[0].name = "Fixed Dominion".to_string();
+        state.replace(build_set_data_from_sets(sets));
+
+        assert_eq!(state.current().id_set_map[&1].name, "Fixed Dominion");
+        assert_eq!(before.id_set_map[&1].name, "Dominion");
+    }
+}
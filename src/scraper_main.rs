@@ -1,52 +1,152 @@
 pub mod model;
 
+use std::{fs, path::Path, process::ExitCode};
+
 use self::model::data_loaders::set_data_loader::get_set_vec;
 use self::model::data_loaders::card_data_loader::get_all_card_vec;
-use self::model::scrapers::scrape_set_files::scrape_set_files;
-use self::model::scrapers::scrape_card_files::scrape_card_files;
+use self::model::scrapers::scrape_engine::{DownloadJob, ScrapeOptions, ScrapeReport, pending_jobs, prune_files, run_scrape, verify_files};
+use self::model::scrapers::scrape_set_files::set_download_jobs;
+use self::model::scrapers::scrape_card_files::card_download_jobs;
+use self::model::scrapers::scraper_cli::{ScraperArgs, ScraperCommand, USAGE, parse_scraper_args};
+use self::model::scrapers::wiki_cards::{draft_from_wiki_dump, write_draft_files};
+
+
+fn all_set_jobs(output_dir: &Path) -> Vec<DownloadJob> {
+    get_set_vec().iter().flat_map(|set| set_download_jobs(set, output_dir)).collect()
+}
+
+fn all_card_jobs(output_dir: &Path) -> Vec<DownloadJob> {
+    get_all_card_vec().iter().flat_map(|card| card_download_jobs(card, output_dir)).collect()
+}
 
+/// The files a command works on, an unknown card name or set id is an error.
+fn command_jobs(command: &ScraperCommand, output_dir: &Path) -> Result<Vec<DownloadJob>, String> {
+    match command {
+        ScraperCommand::Sets => Ok(all_set_jobs(output_dir)),
+        ScraperCommand::Cards => Ok(all_card_jobs(output_dir)),
+        ScraperCommand::Card(name) => get_all_card_vec().iter()
+            .find(|card| card.name.eq_ignore_ascii_case(name))
+            .map(|card| card_download_jobs(card, output_dir))
+            .ok_or_else(|| format!("No card is named {}.", name)),
+        ScraperCommand::Set(set_id) => get_set_vec().iter()
+            .find(|set| set.id == *set_id)
+            .map(|set| set_download_jobs(set, output_dir))
+            .ok_or_else(|| format!("No set has id {}.", set_id)),
+        ScraperCommand::Verify | ScraperCommand::Prune => {
+            Ok(all_set_jobs(output_dir).into_iter().chain(all_card_jobs(output_dir)).collect())
+        },
+        ScraperCommand::Wiki(_) => Ok(vec![]),
+    }
+}
 
 #[mutants::skip]
-#[allow(dead_code)]
-async fn get_all_set_files(){
-    println!("Reading Sets...");
-    let sets = get_set_vec();
-    println!("Loaded {} sets", sets.len());
-    let mut set_count: u8 = 0; 
-    for set in sets.clone(){
-        
-        println!("Scraping {}", set.name);
-        match scrape_set_files(&set).await {
-            Ok(_) => set_count+= 1,
-            Err(error) => println!("Had Error {:?}!", error),
-        }
+fn print_report(report: &ScrapeReport, options: &ScrapeOptions) {
+    println!(
+        "Downloaded {}, skipped {} already scraped and {} failed of {} files",
+        report.downloaded,
+        report.skipped,
+        report.failures.len(),
+        report.total()
+    );
+    for failure in report.failures.iter() {
+        println!("Failed {} after {} attempts: {}", failure.url, failure.attempts, failure.error);
+    }
+    if !report.failures.is_empty() {
+        println!("Failures written to {}", options.report_path.display());
     }
-    println!("Compleated {}/{} sets without problems", set_count ,sets.len());
 }
 
+/// Drafts card records from a wiki dump and prints how they differ from the raw data.
 #[mutants::skip]
-#[allow(dead_code)]
-async fn get_all_card_files(){
-    println!("Reading cards...");
-    let cards = get_all_card_vec();
-    println!("Loaded {} cards", cards.len());
-    let mut card_count: u8 = 0; 
-    for card in cards.clone(){
-        
-        println!("Scraping {}", card.name);
-        match scrape_card_files(&card).await {
-            Ok(_) => card_count+= 1,
-            Err(error) => println!("Had Error {:?}!", error),
+fn draft_from_wiki(dump_path: &Path, draft_dir: &Path, dry_run: bool) -> Result<bool, String> {
+    let xml = fs::read_to_string(dump_path).map_err(|error| format!("Could not read {} {}", dump_path.display(), error))?;
+    let (cards, sets) = (get_all_card_vec(), get_set_vec());
+    let draft = draft_from_wiki_dump(&xml, &cards, &sets);
+    for diff in draft.diffs.iter() {
+        println!("{}", diff);
+    }
+    for problem in draft.problems.iter() {
+        println!("{}", problem);
+    }
+    println!(
+        "Read {} pages, drafted {} cards with {} differences and {} problems",
+        draft.pages,
+        draft.cards.len(),
+        draft.diffs.len(),
+        draft.problems.len()
+    );
+    if !dry_run {
+        let written = write_draft_files(&draft, &cards, &sets, draft_dir).map_err(|error| error.to_string())?;
+        for path in written.iter() {
+            println!("Drafts written to {}", path.display());
         }
     }
-    println!("Compleated {}/{} cards without problems", card_count ,cards.len());
+    Ok(draft.problems.is_empty())
 }
 
+/// Runs a parsed command, returns whether everything it did succeeded.
+#[mutants::skip]
+async fn run(args: ScraperArgs) -> Result<bool, String> {
+    if let ScraperCommand::Wiki(dump_path) = &args.command {
+        return draft_from_wiki(dump_path, &args.draft_dir, args.dry_run);
+    }
+    let options = ScrapeOptions { concurrency: args.concurrency, ..ScrapeOptions::for_output_dir(&args.output_dir) };
+    let jobs = command_jobs(&args.command, &args.output_dir)?;
+
+    match args.command {
+        ScraperCommand::Verify => {
+            let problems = verify_files(&jobs, &options.manifest_path).map_err(|error| error.to_string())?;
+            for problem in problems.iter() {
+                println!("{}", problem);
+            }
+            println!("Checked {} files, found {} problems", jobs.len(), problems.len());
+            Ok(problems.is_empty())
+        },
+        ScraperCommand::Prune => {
+            let pruned = prune_files(&jobs, &options, args.dry_run).map_err(|error| error.to_string())?;
+            for path in pruned.iter() {
+                println!("{}", path.display());
+            }
+            match args.dry_run {
+                true => println!("Would delete {} files", pruned.len()),
+                false => println!("Deleted {} files", pruned.len()),
+            }
+            Ok(true)
+        },
+        _ if args.dry_run => {
+            let total = jobs.len();
+            let pending = pending_jobs(jobs, &options.manifest_path).map_err(|error| error.to_string())?;
+            for job in pending.iter() {
+                println!("{} -> {}", job.url, job.path.display());
+            }
+            println!("Would download {} of {} files", pending.len(), total);
+            Ok(true)
+        },
+        _ => {
+            let report = run_scrape(jobs, &options).await.map_err(|error| format!("Could not run the scrape {}", error))?;
+            print_report(&report, &options);
+            Ok(report.failures.is_empty())
+        },
+    }
+}
 
 #[mutants::skip]
-#[allow(dead_code)]
 #[tokio::main]
-async fn main() {
-    //get_all_set_files().await;
-    get_all_card_files().await;
+async fn main() -> ExitCode {
+    let args = match parse_scraper_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            println!("{}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        },
+    };
+
+    match run(args).await {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            println!("{}", error);
+            ExitCode::FAILURE
+        },
+    }
 }
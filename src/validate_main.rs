@@ -0,0 +1,52 @@
+pub mod model;
+
+use std::process::ExitCode;
+
+use self::model::card_validation::{sets_without_card_data, validate_card_data, validate_landscape_data};
+use self::model::data_loaders::{
+    card_data_loader::load_all_card_vec,
+    data_dir::DataDir,
+    landscape_data_loader::load_landscape_vec,
+    set_data_loader::load_set_vec
+};
+
+
+/// Checks the card, landscape and set JSON, pass a data directory to check it instead of the embedded data.
+#[mutants::skip]
+fn main() -> ExitCode {
+    let data_dir = match std::env::args().nth(1) {
+        Some(path) => DataDir::new(path),
+        None => DataDir::embedded(),
+    };
+
+    let loaded = (load_all_card_vec(&data_dir), load_landscape_vec(&data_dir), load_set_vec(&data_dir));
+    let (cards, landscapes, sets) = match loaded {
+        (Ok(cards), Ok(landscapes), Ok(sets)) => (cards, landscapes, sets),
+        (cards, landscapes, sets) => {
+            for error in [cards.err(), landscapes.err(), sets.err()].into_iter().flatten() {
+                println!("{}", error);
+            }
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let mut errors = validate_card_data(&cards, &sets);
+    errors.extend(validate_landscape_data(&landscapes, &sets));
+    for error in errors.iter() {
+        println!("{}", error);
+    }
+    for set in sets_without_card_data(&cards, &sets) {
+        println!("Set {} has no card data yet.", set.name);
+    }
+    println!(
+        "Checked {} cards, {} landscapes and {} sets, found {} problems",
+        cards.len(),
+        landscapes.len(),
+        sets.len(),
+        errors.len()
+    );
+    match errors.is_empty() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}